@@ -0,0 +1,178 @@
+use crate::board::{Board, Connectivity, Coord};
+use std::mem;
+
+/// What a rule sees of one cell: its value and its neighborhood in the current generation.
+pub struct CellView<'a, T> {
+    board: &'a Board<T>,
+    coord: Coord,
+    offsets: &'a [Coord],
+}
+
+impl<'a, T> CellView<'a, T> {
+    pub fn coord(&self) -> Coord {
+        self.coord
+    }
+
+    pub fn value(&self) -> &'a T {
+        &self.board[self.coord]
+    }
+
+    /// Value at the `i`-th offset of the neighborhood, `None` past a bounded edge.
+    pub fn neighbor(&self, i: usize) -> Option<&'a T> {
+        self.board.get_value(self.coord + self.offsets[i])
+    }
+
+    pub fn neighbors(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let (board, coord) = (self.board, self.coord);
+        self.offsets
+            .iter()
+            .filter_map(move |offset| board.get_value(coord + *offset))
+    }
+
+    pub fn count(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        self.neighbors().filter(|value| predicate(value)).count()
+    }
+}
+
+/// Steps a board through generations of a local rule. Two boards are swapped
+/// between steps, so stepping does not allocate. Edges follow the board's topology.
+pub struct Automaton<T, F> {
+    current: Board<T>,
+    next: Board<T>,
+    offsets: Vec<Coord>,
+    rule: F,
+    generation: usize,
+}
+
+impl<T, F> Automaton<T, F>
+where
+    T: Clone + PartialEq,
+    F: Fn(&CellView<'_, T>) -> T,
+{
+    pub fn new(board: Board<T>, connectivity: Connectivity, rule: F) -> Self {
+        Self::with_offsets(board, connectivity.deltas().to_vec(), rule)
+    }
+
+    /// An automaton whose neighborhood is an arbitrary list of offsets, in the
+    /// order [`CellView::neighbor`] indexes them.
+    pub fn with_offsets(board: Board<T>, offsets: Vec<Coord>, rule: F) -> Self {
+        Self {
+            next: board.clone(),
+            current: board,
+            offsets,
+            rule,
+            generation: 0,
+        }
+    }
+
+    pub fn board(&self) -> &Board<T> {
+        &self.current
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Advances one generation and reports whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        let offsets = &self.offsets;
+        let mut changed = false;
+        for (coord, cell) in self.next.iter_mut() {
+            let view = CellView {
+                board: &self.current,
+                coord,
+                offsets,
+            };
+            let value = (self.rule)(&view);
+            changed |= value != *view.value();
+            *cell = value;
+        }
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// Steps until a generation changes nothing and returns that generation,
+    /// or `None` if it has not settled after `max_generations` steps.
+    pub fn run_until_stable(&mut self, max_generations: usize) -> Option<usize> {
+        for _ in 0..max_generations {
+            if !self.step() {
+                return Some(self.generation - 1);
+            }
+        }
+        None
+    }
+
+    pub fn into_board(self) -> Board<T> {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Topology;
+
+    fn life(cell: &CellView<'_, char>) -> char {
+        match (cell.value(), cell.count(|c| *c == '#')) {
+            ('#', 2) | (_, 3) => '#',
+            _ => '.',
+        }
+    }
+
+    #[test]
+    fn test_blinker_oscillates() {
+        let board = Board::from_string(".....\n..#..\n..#..\n..#..\n.....").unwrap();
+        let mut automaton = Automaton::new(board.clone(), Connectivity::Eight, life);
+        assert!(automaton.step());
+        assert_eq!(
+            automaton.board().to_string(),
+            ".....\n.....\n.###.\n.....\n....."
+        );
+        automaton.step();
+        assert_eq!(automaton.board(), &board);
+        assert_eq!(automaton.run_until_stable(10), None);
+        assert_eq!(automaton.generation(), 12);
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let block = Board::from_string("....\n.##.\n.##.\n....").unwrap();
+        let mut automaton = Automaton::new(block, Connectivity::Eight, life);
+        assert_eq!(automaton.run_until_stable(5), Some(0));
+
+        let board = Board::parse_int_board("9000\n0000\n0000").unwrap();
+        let spread = |cell: &CellView<'_, i32>| {
+            let best = cell.neighbors().max().copied().unwrap_or(0);
+            (*cell.value()).max(best - 1)
+        };
+        let mut automaton = Automaton::new(board, Connectivity::Four, spread);
+        assert_eq!(automaton.run_until_stable(10), Some(5));
+        assert_eq!(automaton.into_board().to_string(), "9876\n8765\n7654");
+    }
+
+    #[test]
+    fn test_toroidal_glider_and_custom_offsets() {
+        let board = Board::from_string(".#....\n..#...\n###...\n......\n......\n......")
+            .unwrap()
+            .with_topology(Topology::Toroidal);
+        let mut automaton = Automaton::new(board.clone(), Connectivity::Eight, life);
+        automaton.run(24);
+        assert_eq!(automaton.board(), &board);
+
+        let shift_right = vec![Coord::new(-1, 0)];
+        let board = Board::from_string("ab.")
+            .unwrap()
+            .with_topology(Topology::Toroidal);
+        let mut automaton =
+            Automaton::with_offsets(board, shift_right, |cell| *cell.neighbor(0).unwrap());
+        automaton.step();
+        assert_eq!(automaton.board().to_string(), ".ab");
+    }
+}
@@ -1,9 +1,10 @@
 use regex::Regex;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
+use AdventOfCode::coord::Coord;
 
 type Value = i64;
-fn read_file(path: &str) -> Vec<Vec<Value>> {
+fn read_file(path: &str) -> Vec<Coord<Value>> {
     let file = File::open(path);
     let reader = BufReader::new(file.unwrap());
     let re = Regex::new(r"\d+").unwrap();
@@ -16,10 +17,10 @@ fn read_file(path: &str) -> Vec<Vec<Value>> {
                 .captures_iter(&line)
                 .map(|c| c[0].parse::<Value>().unwrap())
                 .collect();
-            if captures.is_empty() {
-                None
+            if captures.len() == 2 {
+                Some(Coord::new(captures[0], captures[1]))
             } else {
-                Some(captures)
+                None
             }
         })
         .collect();
@@ -27,45 +28,32 @@ fn read_file(path: &str) -> Vec<Vec<Value>> {
     lines
 }
 
-fn solve_equations(xs: &Vec<Value>, ys: &Vec<Value>, res: &Vec<Value>) -> Option<(Value, Value)> {
-    let (a1, b1, c1) = (xs[0], ys[0], res[0]);
-    let (a2, b2, c2) = (xs[1], ys[1], res[1]);
-
-    let det = a1 * b2 - a2 * b1;
+fn solve_equations(a: Coord<Value>, b: Coord<Value>, prize: Coord<Value>) -> Option<(Value, Value)> {
+    let det = a.x * b.y - a.y * b.x;
 
     if det == 0 {
         return None;
     }
 
-    // Calculate x and y using Cramer's Rule
-    let x: f64 = (c1 * b2 - c2 * b1) as f64 / det as f64;
-    let y: f64 = (a1 * c2 - a2 * c1) as f64 / det as f64;
+    // Calculate the presses using Cramer's Rule, keeping only whole solutions
+    let x = prize.x * b.y - prize.y * b.x;
+    let y = a.x * prize.y - a.y * prize.x;
 
-    if x.fract() == 0.0 && y.fract() == 0.0 {
-        Some((x as Value, y as Value))
+    if x % det == 0 && y % det == 0 {
+        Some((x / det, y / det))
     } else {
         None
     }
 }
 
-fn day13(movements: &Vec<Vec<Value>>, part2: bool) -> Value {
+fn day13(machines: &Vec<Coord<Value>>, part2: bool) -> Value {
     let increment: Value = 10000000000000;
-    let result = movements
-        .chunks(3)
-        .filter_map(|chunk| {
-            if chunk.len() == 3 {
-                if part2 {
-                    let result: Vec<Value> = chunk[2].iter().map(|&x| x + increment).collect();
-                    solve_equations(&chunk[0], &chunk[1], &result)
-                } else {
-                    solve_equations(&chunk[0], &chunk[1], &chunk[2])
-                }
-            } else {
-                None
-            }
-        })
-        .collect::<Vec<(Value, Value)>>();
-    result.iter().map(|(x, y)| *x * 3 + *y).sum()
+    let offset = if part2 { Coord::new(increment, increment) } else { Coord::new(0, 0) };
+    machines
+        .chunks_exact(3)
+        .filter_map(|chunk| solve_equations(chunk[0], chunk[1], chunk[2] + offset))
+        .map(|(x, y)| x * 3 + y)
+        .sum()
 }
 
 fn main() {
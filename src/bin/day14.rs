@@ -3,20 +3,16 @@ use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use AdventOfCode::board::Coord;
+use AdventOfCode::cycle::find_cycle_brent;
+use AdventOfCode::rect::Rect;
+use AdventOfCode::sparse_board::SparseBoard;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 struct Robot {
     position: Coord,
     movement: Coord,
 }
 
-enum Quadrants {
-    TopLeft,
-    TopRight,
-    BottomLeft,
-    BottomRight,
-}
-
 const WIDTH: i32 = 101;
 const HEIGHT: i32 = 103;
 fn read_file(path: &str) -> HashMap<i32, Robot> {
@@ -55,21 +51,20 @@ fn read_file(path: &str) -> HashMap<i32, Robot> {
 }
 
 fn print_matrix(robots: &HashMap<i32, Robot>) {
-    let mut matrix = vec![vec!['.'; WIDTH as usize]; HEIGHT as usize];
+    let mut matrix = SparseBoard::new('.');
 
     for robot in robots.values() {
-        let x = robot.position.x as usize;
-        let y = robot.position.y as usize;
-        if matrix[y][x] == '.' {
-            matrix[y][x] = '1';
+        let cell = &mut matrix[robot.position];
+        if *cell == '.' {
+            *cell = '1';
         } else {
-            let count = matrix[y][x].to_digit(10).unwrap() + 1;
-            matrix[y][x] = std::char::from_digit(count, 10).unwrap();
+            let count = cell.to_digit(10).unwrap() + 1;
+            *cell = std::char::from_digit(count, 10).unwrap();
         }
     }
 
-    for row in matrix {
-        println!("{}", row.iter().collect::<String>());
+    if let Some((board, _)) = matrix.to_board() {
+        board.print_board();
     }
 }
 
@@ -91,30 +86,8 @@ fn generate_first_row(robots: &HashMap<i32, Robot>) -> Vec<char> {
 }
 
 fn do_move(robot: &Robot) -> Robot {
-    let mut new_position = Coord {
-        x: robot.position.x + robot.movement.x,
-        y: robot.position.y + robot.movement.y,
-    };
-
-    new_position = Coord {
-        x: if new_position.x < 0 {
-            WIDTH + (new_position.x % WIDTH)
-        } else if new_position.x >= WIDTH {
-            new_position.x % WIDTH
-        } else {
-            new_position.x
-        },
-        y: if new_position.y < 0 {
-            HEIGHT + (new_position.y % HEIGHT)
-        } else if new_position.y >= HEIGHT {
-            new_position.y % HEIGHT
-        } else {
-            new_position.y
-        },
-    };
-
     Robot {
-        position: new_position,
+        position: (robot.position + robot.movement).rem_euclid(Coord::new(WIDTH, HEIGHT)),
         movement: robot.movement,
     }
 }
@@ -127,44 +100,21 @@ fn move_robots(robots: &HashMap<i32, Robot>) -> HashMap<i32, Robot> {
     new_robots
 }
 
-fn check_quadrant(robot: &Robot) -> Option<Quadrants> {
-    let in_left = robot.position.x < WIDTH / 2;
-    let in_top = robot.position.y < HEIGHT / 2;
-    let in_right = robot.position.x > WIDTH / 2;
-    let in_bottom = robot.position.y > HEIGHT / 2;
-    match (in_left, in_top, in_right, in_bottom) {
-        (true, true, false, false) => Some(Quadrants::TopLeft),
-        (false, true, true, false) => Some(Quadrants::TopRight),
-        (true, false, false, true) => Some(Quadrants::BottomLeft),
-        (false, false, true, true) => Some(Quadrants::BottomRight),
-        _ => None,
-    }
-}
-
 fn part1(robots: &HashMap<i32, Robot>, seconds: i32) -> i32 {
-    let mut robots = (0..seconds).fold(robots.clone(), |curr_robot, _| move_robots(&curr_robot));
-
-    robots
-        .values()
-        .map(|robot| match check_quadrant(&robot) {
-            Some(q) => match q {
-                Quadrants::TopLeft => (1, 0, 0, 0),
-                Quadrants::TopRight => (0, 1, 0, 0),
-                Quadrants::BottomLeft => (0, 0, 1, 0),
-                Quadrants::BottomRight => (0, 0, 0, 1),
-            },
-            None => (0, 0, 0, 0),
+    let robots = (0..seconds).fold(robots.clone(), |curr_robot, _| move_robots(&curr_robot));
+    let room = Rect::with_size(Coord::new(0, 0), WIDTH, HEIGHT).unwrap();
+
+    room.quadrants_excluding_center()
+        .iter()
+        .map(|quadrant| {
+            quadrant.map_or(0, |quadrant| {
+                robots
+                    .values()
+                    .filter(|robot| quadrant.contains(robot.position))
+                    .count() as i32
+            })
         })
-        .reduce(|acc, quadrant| {
-            (
-                acc.0 + quadrant.0,
-                acc.1 + quadrant.1,
-                acc.2 + quadrant.2,
-                acc.3 + quadrant.3,
-            )
-        })
-        .map(|(q1, q2, q3, q4)| q1 * q2 * q3 * q4)
-        .unwrap_or(0)
+        .product()
 }
 
 fn find_pattern(robots: &HashMap<i32, Robot>) -> bool {
@@ -195,23 +145,26 @@ fn find_pattern(robots: &HashMap<i32, Robot>) -> bool {
     false
 }
 
-fn part2(robots: &HashMap<i32, Robot>) -> i32 {
+fn part2(robots: &HashMap<i32, Robot>) -> Option<usize> {
+    let cycle = find_cycle_brent(robots.clone(), |robots| Some(move_robots(robots)))?;
     let mut robots = robots.clone();
-    let mut count =0;
-    loop {
-        count +=1;
+    for count in 1..=cycle.tail + cycle.length {
         robots = move_robots(&robots);
         if find_pattern(&robots) {
             println!();
             print_matrix(&robots);
             println!();
-            return count;
+            return Some(count);
         }
     }
+    None
 }
 
 fn main() {
     let robots = read_file("data/day14.txt");
     println!("Part 1: {}", part1(&robots, 100));
-    println!("Part 2: {}", part2(&robots))
+    match part2(&robots) {
+        Some(seconds) => println!("Part 2: {}", seconds),
+        None => println!("Part 2: no pattern within one period"),
+    }
 }
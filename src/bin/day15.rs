@@ -1,10 +1,47 @@
-use AdventOfCode::board::{Board, Coord};
-fn read_file(path: &str) -> (Board<char>, Vec<char>) {
+use AdventOfCode::board::{Board, Coord, Direction, Expansion, Tile, Tiles};
+use AdventOfCode::input::sections;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum WarehouseTile {
+    Wall,
+    Floor,
+    Box,
+    BoxLeft,
+    BoxRight,
+    Robot,
+}
+
+impl Tile for WarehouseTile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(WarehouseTile::Wall),
+            '.' => Some(WarehouseTile::Floor),
+            'O' => Some(WarehouseTile::Box),
+            '[' => Some(WarehouseTile::BoxLeft),
+            ']' => Some(WarehouseTile::BoxRight),
+            '@' => Some(WarehouseTile::Robot),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            WarehouseTile::Wall => '#',
+            WarehouseTile::Floor => '.',
+            WarehouseTile::Box => 'O',
+            WarehouseTile::BoxLeft => '[',
+            WarehouseTile::BoxRight => ']',
+            WarehouseTile::Robot => '@',
+        }
+    }
+}
+
+fn read_file(path: &str) -> (Board<WarehouseTile>, Vec<char>) {
     let content = std::fs::read_to_string(path).expect("Failed to read file");
-    let mut parts = content.split("\n\n");
+    let mut parts = sections(&content).into_iter();
 
     let board_str = parts.next().expect("Missing Board part");
-    let board = Board::from_string(board_str);
+    let board = Board::parse_board(board_str, Tiles).expect("Invalid board");
 
     let second_part = parts.next().expect("Missing second part");
     let chars: Vec<char> = second_part.chars().filter(|c| !c.is_whitespace()).collect();
@@ -12,65 +49,35 @@ fn read_file(path: &str) -> (Board<char>, Vec<char>) {
     (board, chars)
 }
 
-fn modify_board(board: &mut Board<char>) {
-    board.rows.iter_mut().for_each(|row| {
-        let mut i = 0;
-        while i < row.len() {
-            match row[i] {
-                '.' => {
-                    row.insert(i + 1, '.');
-                    i += 2;
-                }
-                'O' => {
-                    row[i] = '[';
-                    row.insert(i + 1, ']');
-                    i += 2;
-                }
-                '#' => {
-                    row.insert(i + 1, '#');
-                    i += 2;
-                }
-                '@' => {
-                    row.insert(i + 1, '.');
-                    i += 2;
-                }
-                _ => {
-                    i += 1;
-                }
-            }
-        }
+fn modify_board(board: &mut Board<WarehouseTile>) -> Expansion {
+    let (wide, expansion) = board.expand(2, 1, |tile| match tile {
+        WarehouseTile::Box => vec![WarehouseTile::BoxLeft, WarehouseTile::BoxRight],
+        WarehouseTile::Robot => vec![WarehouseTile::Robot, WarehouseTile::Floor],
+        other => vec![*other, *other],
     });
+    *board = wide;
+    expansion
 }
 
-fn char_to_coord(d: char) -> Option<Coord> {
-    match d {
-        '^' => Some(Coord { x: 0, y: -1 }),
-        'v' => Some(Coord { x: 0, y: 1 }),
-        '<' => Some(Coord { x: -1, y: 0 }),
-        '>' => Some(Coord { x: 1, y: 0 }),
-        _ => None,
-    }
-}
-
-fn swap_values(board: &mut Board<char>, coord1: &Coord, coord2: &Coord) {
+fn swap_values(board: &mut Board<WarehouseTile>, coord1: &Coord, coord2: &Coord) {
     let coord1_value = *board.get_value(*coord1).unwrap();
     board.set_value(*coord1, *board.get_value(*coord2).unwrap());
     board.set_value(*coord2, coord1_value);
 }
 
 fn push_box(
-    board: &mut Board<char>,
+    board: &mut Board<WarehouseTile>,
     coord: &Coord,
     movement: &Coord,
     first_coordinate: &Coord,
 ) -> bool {
     let next_coord = *coord + *movement;
     match board.get_value(next_coord) {
-        Some('.') => {
+        Some(WarehouseTile::Floor) => {
             swap_values(board, coord, &next_coord);
             true
         }
-        Some('O') => {
+        Some(WarehouseTile::Box) => {
             let result = push_box(board, &next_coord, movement, first_coordinate);
             swap_values(board, coord, &next_coord);
             result
@@ -80,7 +87,7 @@ fn push_box(
 }
 
 fn push_large_box(
-    board: &mut Board<char>,
+    board: &mut Board<WarehouseTile>,
     part1: &Coord,
     part2: &Coord,
     movement: &Coord,
@@ -90,15 +97,15 @@ fn push_large_box(
     if movement.y == 0 {
         let part2_value = board.get_value(part2_next);
         match part2_value {
-            Some('.') => {
+            Some(WarehouseTile::Floor) => {
                 swap_values(board, part2, &part2_next);
                 swap_values(board, part1, part2);
                 true
             }
-            Some('[') | Some(']') => {
+            Some(WarehouseTile::BoxLeft | WarehouseTile::BoxRight) => {
                 let next_part = match part2_value {
-                    Some('[') => part2_next.right(),
-                    Some(']') => part2_next.left(),
+                    Some(WarehouseTile::BoxLeft) => part2_next.right(),
+                    Some(WarehouseTile::BoxRight) => part2_next.left(),
                     _ => part2_next
                 };
                 let result = push_large_box(board, &part2_next, &next_part, movement, first_coordinate);
@@ -114,22 +121,25 @@ fn push_large_box(
         let part2_value = board.get_value(part2_next);
 
         match (part1_value, part2_value) {
-            (Some('.'), Some('.')) => {
+            (Some(WarehouseTile::Floor), Some(WarehouseTile::Floor)) => {
                 swap_values(board, part2, &part2_next);
                 swap_values(board, part1, &part1_next);
                 true
             }
 
-            (Some('[') | Some(']') | Some('.'), Some('[') | Some(']') | Some('.')) => {
+            (
+                Some(WarehouseTile::BoxLeft | WarehouseTile::BoxRight | WarehouseTile::Floor),
+                Some(WarehouseTile::BoxLeft | WarehouseTile::BoxRight | WarehouseTile::Floor),
+            ) => {
                 let (next_part, next_part_2) = (
                     match part1_value {
-                        Some('[') => part1_next.right(),
-                        Some(']') => part1_next.left(),
+                        Some(WarehouseTile::BoxLeft) => part1_next.right(),
+                        Some(WarehouseTile::BoxRight) => part1_next.left(),
                         _ => part1_next,
                     },
                     match part2_value {
-                        Some('[') => part2_next.right(),
-                        Some(']') => part2_next.left(),
+                        Some(WarehouseTile::BoxLeft) => part2_next.right(),
+                        Some(WarehouseTile::BoxRight) => part2_next.left(),
                         _ => part2_next,
                     },
                 );
@@ -160,29 +170,29 @@ fn push_large_box(
     }
 }
 
-fn next_state(board: &mut Board<char>, coord: &mut Coord, move_dir: &char) {
-    let movement = char_to_coord(move_dir.clone()).unwrap();
+fn next_state(board: &mut Board<WarehouseTile>, coord: &mut Coord, move_dir: &char) {
+    let movement = Direction::from_char(*move_dir).unwrap().to_coord();
     let next_coord = movement + coord.clone();
     let board_copy = board.clone();
     match board.get_value(next_coord) {
-        Some('.') => {
+        Some(WarehouseTile::Floor) => {
             *coord = next_coord
         }
-        Some('O') => {
+        Some(WarehouseTile::Box) => {
             if push_box(board, &next_coord, &movement, &next_coord){
                 *coord = next_coord
             }else{
                 *board = board_copy;
             }
         }
-        Some('[') => {
+        Some(WarehouseTile::BoxLeft) => {
             if push_large_box(board, &next_coord, &next_coord.right(), &movement, &next_coord) {
                 *coord = next_coord;
             }else{
                 *board = board_copy;
             }
         }
-        Some(']') => {
+        Some(WarehouseTile::BoxRight) => {
             if push_large_box(board, &next_coord, &next_coord.left(), &movement, &next_coord) {
                 *coord = next_coord;
             }else{
@@ -193,26 +203,16 @@ fn next_state(board: &mut Board<char>, coord: &mut Coord, move_dir: &char) {
     }
 }
 
-fn count_coords(board: &Board<char>, element: char) -> i32 {
+fn count_coords(board: &Board<WarehouseTile>, element: WarehouseTile) -> i32 {
     board
-        .rows
-        .iter()
-        .enumerate()
-        .flat_map(|(y, row)| {
-            row.iter().enumerate().filter_map(move |(x, &cell)| {
-                if cell == element {
-                    Some((y as i32) * 100 + (x as i32))
-                } else {
-                    None
-                }
-            })
-        })
+        .positions_of(&element)
+        .map(|coord| coord.y * 100 + coord.x)
         .sum()
 }
 
-fn day15(board: &Board<char>, chars: &Vec<char>, coord: &Coord, element: char) -> i32 {
+fn day15(board: &Board<WarehouseTile>, chars: &Vec<char>, coord: &Coord, element: WarehouseTile) -> i32 {
     let mut board = board.clone();
-    board.set_value(*coord, '.');
+    board.set_value(*coord, WarehouseTile::Floor);
     let mut coord = coord.clone();
     chars.iter().for_each(|c| {
         next_state(&mut board, &mut coord, c);
@@ -223,12 +223,12 @@ fn day15(board: &Board<char>, chars: &Vec<char>, coord: &Coord, element: char) -
 
 fn main() {
     let (mut board, chars) = read_file("data/day15.txt");
-    let player = board.find_element('@').unwrap();
-    println!("Part 1: {:?}", day15(&board, &chars, &player, 'O'));
+    let player = board.find_element(WarehouseTile::Robot).unwrap();
+    println!("Part 1: {:?}", day15(&board, &chars, &player, WarehouseTile::Box));
 
-    modify_board(&mut board);
-    let player = board.find_element('@').unwrap();
-    println!("Part 2: {:?}", day15(&board, &chars, &player, '['));
+    let expansion = modify_board(&mut board);
+    let player = expansion.to_expanded(player);
+    println!("Part 2: {:?}", day15(&board, &chars, &player, WarehouseTile::BoxLeft));
 }
 
 #[cfg(test)]
@@ -239,15 +239,15 @@ mod tests {
     fn test_day15_part2() {
         let (mut board, chars) = read_file("data/day15test.txt");
         modify_board(&mut board);
-        let player = board.find_element('@').unwrap();
-        assert_eq!(day15(&board, &chars, &player, '['), 9021);
+        let player = board.find_element(WarehouseTile::Robot).unwrap();
+        assert_eq!(day15(&board, &chars, &player, WarehouseTile::BoxLeft), 9021);
     }
 
     #[test]
     fn test_day15_part2_solution() {
         let (mut board, chars) = read_file("data/day15.txt");
         modify_board(&mut board);
-        let player = board.find_element('@').unwrap();
-        assert_eq!(day15(&board, &chars, &player, '['), 1509780);
+        let player = board.find_element(WarehouseTile::Robot).unwrap();
+        assert_eq!(day15(&board, &chars, &player, WarehouseTile::BoxLeft), 1509780);
     }
 }
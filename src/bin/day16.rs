@@ -1,16 +1,16 @@
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet, VecDeque};
-use AdventOfCode::board::{Board, Coord};
+use AdventOfCode::board::{BitBoard, Board, Coord, Direction};
 use AdventOfCode::path_finding::*;
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Copy)]
 struct State {
     coord: Coord,
-    direction: i8,
+    direction: Direction,
 }
 
 impl State {
-    fn new(coord: Coord, direction: i8) -> Self {
+    fn new(coord: Coord, direction: Direction) -> Self {
         Self { coord, direction }
     }
 }
@@ -19,6 +19,10 @@ impl StateTrait for State {
     fn get_coord(&self) -> Coord {
         self.coord.clone()
     }
+
+    fn with_coord(&self, coord: Coord) -> Self {
+        Self::new(coord, self.direction)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Copy)]
@@ -69,25 +73,14 @@ impl PartialOrd<Self> for Node {
     }
 }
 
-// Manhattan distance
 fn heuristic(start: &Coord, end: &State) -> usize {
-    ((end.coord.x - start.x).abs() + (end.coord.y - start.y).abs()) as usize
-}
-
-fn get_coord_from_direction(coord: Coord, direction: i8) -> Coord {
-    match direction {
-        0 => coord.up(),
-        1 => coord.right(),
-        2 => coord.down(),
-        3 => coord.left(),
-        _ => panic!("Invalid direction"),
-    }
+    start.manhattan(end.coord) as usize
 }
 
 fn get_neighbors(node: &Node, end: &State) -> Vec<Node> {
     let coord = node.state.coord;
     let i = node.state.direction;
-    let move_coord = get_coord_from_direction(coord, i);
+    let move_coord = coord.step(i);
     vec![
         Node {
             state: State::new(move_coord, i),
@@ -95,20 +88,20 @@ fn get_neighbors(node: &Node, end: &State) -> Vec<Node> {
             h_cost: heuristic(&move_coord, end),
         },
         Node {
-            state: State::new(coord, (i + 1).rem_euclid(4)),
+            state: State::new(coord, i.turn_right()),
             cost: node.cost + 1000,
             h_cost: node.h_cost,
         },
         Node {
-            state: State::new(coord, (i - 1).rem_euclid(4)),
+            state: State::new(coord, i.turn_left()),
             cost: node.cost + 1000,
             h_cost: node.h_cost,
         },
     ]
 }
 
-fn get_tiles(paths: HashMap<Node, HashSet<Node>>, best_cost: usize, end: &Coord) -> usize {
-    let mut tiles: HashSet<Coord> = HashSet::new();
+fn get_tiles(board: &Board<char>, paths: HashMap<Node, HashSet<Node>>, best_cost: usize, end: &Coord) -> usize {
+    let mut tiles = BitBoard::new(board.width(), board.height());
     let mut visited = HashSet::new();
     let mut queue = VecDeque::new();
 
@@ -120,7 +113,7 @@ fn get_tiles(paths: HashMap<Node, HashSet<Node>>, best_cost: usize, end: &Coord)
     for node in end_nodes {
         let node_clone = node.clone();
         if visited.insert(node_clone) {
-            tiles.insert(node_clone.state.coord);
+            tiles.set(node_clone.state.coord);
             queue.push_back(node_clone);
         }
     }
@@ -130,28 +123,29 @@ fn get_tiles(paths: HashMap<Node, HashSet<Node>>, best_cost: usize, end: &Coord)
             for pred in predecessors {
                 let pred_clone = pred.clone();
                 if visited.insert(pred_clone) {
-                    tiles.insert(pred_clone.state.coord);
+                    tiles.set(pred_clone.state.coord);
                     queue.push_back(pred_clone);
                 }
             }
         }
     }
-    tiles.len()
+    tiles.count_ones()
 }
 
 fn main() {
-    let board = Board::read_char_board("data/day16.txt");
+    let board = Board::read_char_board("data/day16.txt").unwrap();
     let start: Coord = board.find_element('S').unwrap();
     let end: Coord = board.find_element('E').unwrap();
 
     let (part1, paths) = a_star(
         &board,
-        State::new(start, 1),
-        State::new(end, 0),
+        State::new(start, Direction::Right),
+        State::new(end, Direction::Up),
         get_neighbors,
+        |c| *c != '#',
         false
     );
-    let part2 = get_tiles(paths, part1, &end);
+    let part2 = get_tiles(&board, paths, part1, &end);
     println!("Part1 {}", part1);
     println!("Part2: {}", part2);
 }
@@ -159,21 +153,22 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use crate::{a_star, get_neighbors, get_tiles, State};
-    use AdventOfCode::board::{Board, Coord};
+    use AdventOfCode::board::{Board, Coord, Direction};
 
     #[test]
     fn test_part1_maze1() {
-        let board = Board::read_char_board("data/day16test.txt");
+        let board = Board::read_char_board("data/day16test.txt").unwrap();
         let start: Coord = board.find_element('S').unwrap();
         let end: Coord = board.find_element('E').unwrap();
         let (part1, paths) = a_star(
             &board,
-            State::new(start, 1),
-            State::new(end, 0),
+            State::new(start, Direction::Right),
+            State::new(end, Direction::Up),
             get_neighbors,
+            |c| *c != '#',
             false
         );
-        let part2 = get_tiles(paths, part1, &end);
+        let part2 = get_tiles(&board, paths, part1, &end);
         assert_eq!(part1, 7036);
         assert_eq!(part2, 45);
     }
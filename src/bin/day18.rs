@@ -1,7 +1,7 @@
 use std::cmp::Ordering;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
-use AdventOfCode::board::{Board, Coord};
+use AdventOfCode::board::{BitBoard, Coord};
 use AdventOfCode::path_finding::{a_star, NodeTrait, StateTrait};
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Copy)]
@@ -19,6 +19,10 @@ impl StateTrait for State {
     fn get_coord(&self) -> Coord {
         self.coord.clone()
     }
+
+    fn with_coord(&self, coord: Coord) -> Self {
+        Self::new(coord)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Copy)]
@@ -88,12 +92,14 @@ fn read_input(path: &str) -> Vec<Coord> {
     coords
 }
 
-fn add_coords(board: &mut Board<char>, coords: &[Coord]) {
-    coords.iter().for_each(|coord| board.set_value(*coord, '#'))
+fn add_coords(walls: &mut BitBoard, coords: &[Coord]) {
+    for coord in coords {
+        walls.set(*coord);
+    }
 }
 
 fn heuristic(start: &Coord, end: &State) -> usize {
-    ((end.coord.x - start.x).abs() + (end.coord.y - start.y).abs()) as usize
+    start.manhattan(end.coord) as usize
 }
 
 fn get_neighbors(node: &Node, end: &State) -> Vec<Node> {
@@ -124,25 +130,26 @@ fn get_neighbors(node: &Node, end: &State) -> Vec<Node> {
 }
 
 // Part 1 Function
-fn get_cost(board: &Board<char>, coords: &Vec<Coord>, size: usize, byte: usize) -> usize {
-    let mut board = board.clone();
-    add_coords(&mut board, &coords[0..byte]);
+fn get_cost(walls: &BitBoard, coords: &Vec<Coord>, size: usize, byte: usize) -> usize {
+    let mut walls = walls.clone();
+    add_coords(&mut walls, &coords[0..byte]);
     let (cost, _) = a_star(
-        &board,
+        &walls,
         State::new(Coord::new(0, 0)),
         State::new(Coord::new((size - 1) as i32, (size - 1) as i32)),
         get_neighbors,
+        |wall| !*wall,
         false,
     );
     cost
 }
 
-fn part2(board: &Board<char>, coords: &Vec<Coord>, size: usize) -> Coord {
+fn part2(walls: &BitBoard, coords: &Vec<Coord>, size: usize) -> Coord {
     let mut ranges: (usize, usize) = (0, coords.len()-1);
 
     while ranges.0 != ranges.1 - 1{
         let pos = (ranges.1 - ranges.0)/2 + ranges.0;
-        let cost = get_cost(board, coords, size, pos);
+        let cost = get_cost(walls, coords, size, pos);
         if cost == usize::MAX {
             ranges.1 = pos;
         }else {
@@ -154,32 +161,32 @@ fn part2(board: &Board<char>, coords: &Vec<Coord>, size: usize) -> Coord {
 
 fn main() {
     let size = 71;
-    let board = Board::<char>::empty(size, size);
+    let walls = BitBoard::new(size, size);
     let coords = read_input("data/day18.txt");
-    let tiles = get_cost(&board, &coords, size, 1024);
-    let coord_par2 = part2(&board, &coords, size);
+    let tiles = get_cost(&walls, &coords, size, 1024);
+    let coord_par2 = part2(&walls, &coords, size);
     println!("Part 1: {}", tiles);
     println!("Part 2: {:?}", coord_par2);
 }
 
 #[cfg(test)]
 mod tests {
-    use AdventOfCode::board::{Board, Coord};
+    use AdventOfCode::board::{BitBoard, Coord};
     use crate::{get_cost, part2, read_input};
 
     #[test]
     fn test_part1() {
         let size = 7;
-        let board = Board::<char>::empty(size, size);
+        let walls = BitBoard::new(size, size);
         let coords = read_input("data/day18test.txt");
-        assert_eq!(get_cost(&board, &coords, size, 12), 22);
+        assert_eq!(get_cost(&walls, &coords, size, 12), 22);
     }
 
     #[test]
     fn test_part2() {
         let size = 7;
-        let board = Board::<char>::empty(7, 7);
+        let walls = BitBoard::new(7, 7);
         let coords = read_input("data/day18test.txt");
-        assert_eq!(part2(&board, &coords, size), Coord { x: 6, y: 1 });
+        assert_eq!(part2(&walls, &coords, size), Coord { x: 6, y: 1 });
     }
 }
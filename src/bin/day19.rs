@@ -1,8 +1,9 @@
 use std::collections::HashMap;
+use AdventOfCode::input::sections;
 
 fn read_file(path: &str) -> (Vec<String>, Vec<String>) {
     let content = std::fs::read_to_string(path).expect("Failed to read file");
-    let mut parts = content.split("\n\n");
+    let mut parts = sections(&content).into_iter();
 
     let sequence = parts.next().expect("Missing sequence");
     let designs: Vec<String> = sequence.split(",").map(|c| c.trim().to_string()).collect();
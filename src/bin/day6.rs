@@ -1,52 +1,40 @@
 use std::collections::HashSet;
-use AdventOfCode::board::{Board, Coord};
+use AdventOfCode::board::{Board, Coord, Direction};
+use AdventOfCode::cycle::find_cycle_brent;
 
 fn find_player(board: &Board<char>) -> Option<Coord> {
     let targets = ['<', '>', '^', 'v'];
 
     board
-        .rows
         .iter()
-        .enumerate()
-        .find_map(|(row_idx, row)| {
-            row.iter()
-                .enumerate()
-                .find(|&(_, &cell)| targets.contains(&cell))
-                .map(|(col_idx, _)| Coord::new(
-                    col_idx as i32,
-                    row_idx as i32,
-                ))
-        })
+        .find(|(_, cell)| targets.contains(cell))
+        .map(|(coord, _)| coord)
 }
 
 
-fn get_direction(board: &Board<char>, player: Coord) -> usize {
-    match board.get_value(player) {
-        Some('^') => 0,
-        Some('>') => 1,
-        Some('v') => 2,
-        Some('<') => 3,
-        _ => 0,
-    }
+fn get_direction(board: &Board<char>, player: Coord) -> Direction {
+    board.get_value(player).and_then(|c| Direction::from_char(*c)).unwrap_or(Direction::Up)
 }
 
 
-fn loop_game(board: &Board<char>, directions: &[Coord]) -> Option<HashSet<(Coord, usize)>> {
-    let mut visited_positions: HashSet<(Coord, usize)> = HashSet::new();
+fn loop_game(board: &Board<char>) -> Option<HashSet<(Coord, Direction)>> {
+    let mut visited_positions: HashSet<(Coord, Direction)> = HashSet::new();
     let mut coord = find_player(board).unwrap();
     let mut i = get_direction(board, coord);
     loop{
         if !visited_positions.insert((coord, i)){
             return None
         }
-        let next_coord = coord + directions[i];
-
-        if let Some(value) = board.get_value(next_coord) {
-            if value.clone() == '#' {
-                i = (i + 1)%4;
-            }else{
-                coord = next_coord;
+        let obstacle = board.first_hit(coord, i.to_coord(), |c| *c == '#');
+        for (next_coord, _) in board.ray_until(coord, i.to_coord(), |c| *c == '#') {
+            if !visited_positions.insert((next_coord, i)){
+                return None
             }
+            coord = next_coord;
+        }
+
+        if obstacle.is_some() {
+            i = i.turn_right();
         }else{
             break;
         }
@@ -54,14 +42,23 @@ fn loop_game(board: &Board<char>, directions: &[Coord]) -> Option<HashSet<(Coord
     Some(visited_positions)
 }
 
-fn part2(board: &Board<char>, visited: HashSet<Coord>, directions: &[Coord]) -> i32 {
+fn step_guard(board: &Board<char>, (coord, i): &(Coord, Direction)) -> Option<(Coord, Direction)> {
+    let next = coord.step(*i);
+    match board.get_value(next)? {
+        '#' => Some((*coord, i.turn_right())),
+        _ => Some((next, *i)),
+    }
+}
+
+fn part2(board: &Board<char>, visited: HashSet<Coord>) -> i32 {
     let mut count = 0;
     let coord = find_player(board).unwrap();
+    let guard = (coord, get_direction(board, coord));
     for vis in visited{
         if coord != vis{
             let mut new_board = board.clone();
             new_board.set_value(vis, '#');
-            if let None = loop_game(&new_board, &directions){
+            if find_cycle_brent(guard, |g| step_guard(&new_board, g)).is_some(){
                 count += 1;
             }
         }
@@ -70,13 +67,12 @@ fn part2(board: &Board<char>, visited: HashSet<Coord>, directions: &[Coord]) ->
 }
 
 fn main() {
-    let directions = [Coord::new(0, -1), Coord::new(1, 0), Coord::new(0, 1), Coord::new(-1, 0)]; //^, >, v, <
-    let board = Board::read_char_board("data/day6.txt");
-    let visited_positions = loop_game(&board, &directions).unwrap();
+    let board = Board::read_char_board("data/day6.txt").unwrap();
+    let visited_positions = loop_game(&board).unwrap();
     let positions: HashSet<Coord> = visited_positions.into_iter().map(|(coord, _)| coord).collect();
 
     println!("Part 1: {}", positions.iter().count());
-    println!("Part 2: {}", part2(&board, positions, &directions));
+    println!("Part 2: {}", part2(&board, positions));
 
 
 }
\ No newline at end of file
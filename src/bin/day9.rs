@@ -54,7 +54,7 @@ fn generate_disk_map(disk: &Vec<i32>) -> Vec<DiskPosition> {
 }
 
 fn read_file(path: &str) -> Vec<DiskPosition> {
-    let numbers = Board::read_int_board(path).rows.first().unwrap().clone();
+    let numbers = Board::read_int_board(path).unwrap().row(0).to_vec();
     generate_disk_map(&numbers)
 }
 
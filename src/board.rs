@@ -1,84 +1,857 @@
-use std::fmt::{Debug, Display};
+mod bit_board;
+mod direction;
+mod hex;
+mod pattern;
+mod position_index;
+mod ray;
+mod region;
+mod word_search;
+
+pub use bit_board::BitBoard;
+pub use direction::{Direction, Direction8, ParseDirectionError};
+pub use hex::{Hex, HexBoard, HexDirection, OffsetLayout};
+pub use pattern::{Pattern, PatternMatch, Transform};
+pub use position_index::{IndexedBoard, PositionIndex};
+pub use ray::{Hit, Ray};
+pub use region::{Connectivity, Region, RegionId, Regions};
+pub use word_search::{WordMatch, WordSearch};
+
+pub use crate::coord::Coord;
+
+use crate::input;
+use crate::rect::Rect;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
 use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::ops::{Add, Mul, Sub};
+use std::io::{self, BufRead, BufReader};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Topology {
+    #[default]
+    Bounded,
+    Toroidal,
+}
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Board<T> {
-    pub rows: Vec<Vec<T>>,
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    topology: Topology,
 }
 
-impl<T> Board<T>
-where
-    T: PartialEq + Debug + Display + Copy,
-{
-    pub fn new(rows: Vec<Vec<T>>) -> Self {
-        Self { rows }
+impl<T> Board<T> {
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "board of {}x{} needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Self {
+            cells,
+            width,
+            height,
+            topology: Topology::Bounded,
+        }
     }
 
-    pub fn read_board(path: &str, parser: &dyn Fn(&char) -> T) -> Self {
-        let file = File::open(path);
-        let reader = BufReader::new(file.unwrap());
-        let rows = reader
-            .lines()
-            .map(|line| line.unwrap().chars().map(|c| parser(&c)).collect())
-            .collect();
-        Self::new(rows)
+    /// Builds a board from text rows, ignoring leading and trailing blank lines.
+    /// `parse_row` receives the 1-based line number in the input and must return
+    /// every cell of that row; rows are passed in order.
+    fn parse_rows<'a, I, F>(lines: I, mut parse_row: F) -> Result<Self, BoardParseError>
+    where
+        I: Iterator<Item = &'a str>,
+        F: FnMut(usize, &'a str) -> Result<Vec<T>, BoardParseError>,
+    {
+        let lines: Vec<&str> = lines.collect();
+        let Some(first) = lines.iter().position(|line| !line.is_empty()) else {
+            return Err(BoardParseError::Empty);
+        };
+        let last = lines
+            .iter()
+            .rposition(|line| !line.is_empty())
+            .unwrap_or(first);
+
+        let mut cells = Vec::new();
+        let mut width = 0;
+        for (i, line) in lines.iter().enumerate().take(last + 1).skip(first) {
+            let row = parse_row(i + 1, line)?;
+            if i == first {
+                width = row.len();
+            } else if row.len() != width {
+                return Err(BoardParseError::RaggedRow {
+                    line: i + 1,
+                    expected: width,
+                    found: row.len(),
+                });
+            }
+            cells.extend(row);
+        }
+        Ok(Self::from_vec(width, last + 1 - first, cells))
     }
 
-    fn empty_board(width: usize, height: usize, default_value: T) -> Self {
-        let rows = vec![vec![default_value; width]; height];
-        Self::new(rows)
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
     }
 
-    pub fn get_value(&self, coord: Coord) -> Option<&T> {
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.topology = topology;
+    }
+
+    /// Brings a coordinate back onto a toroidal board; bounded boards return it unchanged.
+    pub fn wrap(&self, coord: Coord) -> Coord {
+        match self.topology {
+            Topology::Toroidal if self.width > 0 && self.height > 0 => Coord::new(
+                coord.x.rem_euclid(self.width as i32),
+                coord.y.rem_euclid(self.height as i32),
+            ),
+            _ => coord,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Every in-bounds coordinate, from `(0, 0)` to the bottom-right corner;
+    /// `None` for a board without cells.
+    pub fn rect(&self) -> Option<Rect> {
+        Rect::with_size(Coord::new(0, 0), self.width as i32, self.height as i32)
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> Column<'_, T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        Column { board: self, x }
+    }
+
+    fn index_of(&self, coord: Coord) -> Option<usize> {
+        let coord = self.wrap(coord);
         let x: usize = coord.x.try_into().ok()?;
         let y: usize = coord.y.try_into().ok()?;
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
 
-        self.rows.get(y).and_then(|row| row.get(x))
+    fn coord_of(&self, index: usize) -> Coord {
+        Coord::new((index % self.width) as i32, (index / self.width) as i32)
     }
+
+    pub fn get_value(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|i| &self.cells[i])
+    }
+
+    pub fn get_value_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|i| &mut self.cells[i])
+    }
+
     pub fn set_value(&mut self, coord: Coord, value: T) {
-        let x: usize = coord.x.try_into().ok().unwrap();
-        let y: usize = coord.y.try_into().ok().unwrap();
-        self.rows[y][x] = value;
+        let i = self
+            .index_of(coord)
+            .unwrap_or_else(|| panic!("{:?} is out of bounds", coord));
+        self.cells[i] = value;
     }
 
     pub fn is_in_bound(&self, coord: Coord) -> bool {
+        self.index_of(coord).is_some()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.coord_of(i), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut T)> + '_ {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| (Coord::new((i % width) as i32, (i / width) as i32), cell))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Column<'_, T>> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn neighbors<'a>(
+        &'a self,
+        coord: Coord,
+        deltas: &'a [Coord],
+    ) -> impl Iterator<Item = (Coord, &'a T)> + 'a {
+        Grid::neighbors(self, coord, deltas)
+    }
+
+    pub fn neighbors4(&self, coord: Coord) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        self.neighbors(coord, &NEIGHBORS_4)
+    }
+
+    pub fn neighbors8(&self, coord: Coord) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        self.neighbors(coord, &NEIGHBORS_8)
+    }
+
+    pub fn view(&self) -> BoardView<'_, T> {
+        BoardView {
+            board: self,
+            origin: Coord::new(0, 0),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Borrowed window between two inclusive corners, or `None` if it does not fit.
+    pub fn sub_view(&self, top_left: Coord, bottom_right: Coord) -> Option<BoardView<'_, T>> {
+        self.view().sub_view(top_left, bottom_right)
+    }
+}
+
+impl<T: Clone> Board<T> {
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> usize) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self.cells[source(x, y)].clone())
+            .collect();
+        Self::from_vec(width, height, cells).with_topology(self.topology)
+    }
+
+    /// Rotates the board clockwise.
+    pub fn rotate_90(&self) -> Self {
+        let (w, h) = (self.width, self.height);
+        self.remap(h, w, |x, y| (h - 1 - x) * w + y)
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        let (w, h) = (self.width, self.height);
+        self.remap(w, h, |x, y| (h - 1 - y) * w + (w - 1 - x))
+    }
+
+    pub fn rotate_270(&self) -> Self {
+        let (w, h) = (self.width, self.height);
+        self.remap(h, w, |x, y| x * w + (w - 1 - y))
+    }
+
+    pub fn transpose(&self) -> Self {
+        let (w, h) = (self.width, self.height);
+        self.remap(h, w, |x, y| x * w + y)
+    }
+
+    /// Mirrors the board left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let (w, h) = (self.width, self.height);
+        self.remap(w, h, |x, y| y * w + (w - 1 - x))
+    }
+
+    /// Mirrors the board top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let (w, h) = (self.width, self.height);
+        self.remap(w, h, |x, y| (h - 1 - y) * w + x)
+    }
+
+    pub fn crop(&self, top_left: Coord, bottom_right: Coord) -> Option<Self> {
+        self.sub_view(top_left, bottom_right)
+            .map(|view| view.to_board())
+    }
+}
+
+impl<T> Board<T> {
+    /// Builds a board of the same shape and topology from `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Board<U> {
+        Board::from_vec(self.width, self.height, self.cells.iter().map(f).collect())
+            .with_topology(self.topology)
+    }
+
+    /// Replaces every cell with a `block_width` x `block_height` block of cells,
+    /// given by `mapping` in row-major order. Panics if either size is 0.
+    pub fn expand<U, F>(
+        &self,
+        block_width: usize,
+        block_height: usize,
+        mapping: F,
+    ) -> (Board<U>, Expansion)
+    where
+        F: Fn(&T) -> Vec<U>,
+    {
+        assert!(
+            block_width > 0 && block_height > 0,
+            "expansion blocks of {}x{} cells are empty",
+            block_width,
+            block_height
+        );
+        let width = self.width * block_width;
+        let height = self.height * block_height;
+        let mut blocks: Vec<_> = self
+            .cells
+            .iter()
+            .map(|cell| {
+                let block = mapping(cell);
+                assert_eq!(
+                    block.len(),
+                    block_width * block_height,
+                    "expansion blocks must have {}x{} cells",
+                    block_width,
+                    block_height
+                );
+                block.into_iter()
+            })
+            .collect();
+
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..self.height {
+            for _ in 0..block_height {
+                for block in &mut blocks[y * self.width..(y + 1) * self.width] {
+                    cells.extend(block.by_ref().take(block_width));
+                }
+            }
+        }
+        let board = Board::from_vec(width, height, cells).with_topology(self.topology);
+        let expansion = Expansion {
+            block_width,
+            block_height,
+        };
+        (board, expansion)
+    }
+}
+
+/// Maps coordinates between a board and the result of [`Board::expand`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Expansion {
+    block_width: usize,
+    block_height: usize,
+}
+
+impl Expansion {
+    /// Top-left cell of the block that `coord` expanded into.
+    pub fn to_expanded(&self, coord: Coord) -> Coord {
+        Coord::new(
+            coord.x * self.block_width as i32,
+            coord.y * self.block_height as i32,
+        )
+    }
+
+    /// Original cell whose block contains `coord`.
+    pub fn to_original(&self, coord: Coord) -> Coord {
+        Coord::new(
+            coord.x.div_euclid(self.block_width as i32),
+            coord.y.div_euclid(self.block_height as i32),
+        )
+    }
+}
+
+/// Cell lookup, bounds and neighbors shared by every grid representation.
+pub trait Grid {
+    type Cell;
+
+    fn get_value(&self, coord: Coord) -> Option<&Self::Cell>;
+
+    fn is_in_bound(&self, coord: Coord) -> bool {
         self.get_value(coord).is_some()
     }
 
-    pub fn find_element(&self, element: T) -> Option<Coord> {
-        self.rows.iter().enumerate().find_map(|(row_idx, row)| {
-            row.iter()
+    fn wrap(&self, coord: Coord) -> Coord {
+        coord
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        coord: Coord,
+        deltas: &'a [Coord],
+    ) -> impl Iterator<Item = (Coord, &'a Self::Cell)> + 'a {
+        deltas.iter().filter_map(move |delta| {
+            let neighbor = self.wrap(coord + *delta);
+            self.get_value(neighbor).map(|value| (neighbor, value))
+        })
+    }
+
+    fn neighbors4(&self, coord: Coord) -> impl Iterator<Item = (Coord, &Self::Cell)> + '_ {
+        self.neighbors(coord, &NEIGHBORS_4)
+    }
+
+    fn neighbors8(&self, coord: Coord) -> impl Iterator<Item = (Coord, &Self::Cell)> + '_ {
+        self.neighbors(coord, &NEIGHBORS_8)
+    }
+}
+
+impl<T> Grid for Board<T> {
+    type Cell = T;
+
+    fn get_value(&self, coord: Coord) -> Option<&T> {
+        Board::get_value(self, coord)
+    }
+
+    fn is_in_bound(&self, coord: Coord) -> bool {
+        Board::is_in_bound(self, coord)
+    }
+
+    fn wrap(&self, coord: Coord) -> Coord {
+        Board::wrap(self, coord)
+    }
+}
+
+impl<T> Index<Coord> for Board<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get_value(coord)
+            .unwrap_or_else(|| panic!("{:?} is out of bounds", coord))
+    }
+}
+
+impl<T> IndexMut<Coord> for Board<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        self.get_value_mut(coord)
+            .unwrap_or_else(|| panic!("{:?} is out of bounds", coord))
+    }
+}
+
+impl<T> Board<T>
+where
+    T: PartialEq + Debug + Copy,
+{
+    pub fn new(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "all rows of a board must have the same length"
+        );
+        Self::from_vec(width, height, rows.into_iter().flatten().collect())
+    }
+
+    pub fn read_board<P: CellParser<T>>(path: &str, parser: P) -> Result<Self, BoardParseError> {
+        Self::from_reader(BufReader::new(File::open(path)?), parser)
+    }
+
+    pub fn from_reader<R: BufRead, P: CellParser<T>>(
+        reader: R,
+        parser: P,
+    ) -> Result<Self, BoardParseError> {
+        Self::parse_lines(reader.lines(), parser)
+    }
+
+    pub fn parse_board<P: CellParser<T>>(input: &str, parser: P) -> Result<Self, BoardParseError> {
+        Self::parse_lines(input.lines().map(|line| Ok(line.to_string())), parser)
+    }
+
+    /// Parses the section of a blank-line-separated document headed by `name`.
+    pub fn from_section<P: CellParser<T>>(
+        document: &str,
+        name: &str,
+        parser: P,
+    ) -> Result<Self, BoardParseError> {
+        let body = input::section(document, name)
+            .ok_or_else(|| BoardParseError::MissingSection(name.to_string()))?;
+        Self::parse_board(body, parser)
+    }
+
+    fn parse_lines<I, P>(lines: I, parser: P) -> Result<Self, BoardParseError>
+    where
+        I: Iterator<Item = io::Result<String>>,
+        P: CellParser<T>,
+    {
+        let lines = lines.collect::<io::Result<Vec<String>>>()?;
+        Self::parse_rows(lines.iter().map(String::as_str), |line, row| {
+            row.chars()
                 .enumerate()
-                .find(|&(_, cell)| cell == &element)
-                .map(|(col_idx, _)| Coord::new(col_idx as i32, row_idx as i32))
+                .map(|(x, c)| {
+                    parser.parse_cell(c).ok_or(BoardParseError::InvalidCell {
+                        line,
+                        column: x + 1,
+                        character: c,
+                    })
+                })
+                .collect()
         })
     }
 
+    fn empty_board(width: usize, height: usize, default_value: T) -> Self {
+        Self::from_vec(width, height, vec![default_value; width * height])
+    }
+
+    pub fn find_element(&self, element: T) -> Option<Coord> {
+        self.cells
+            .iter()
+            .position(|cell| cell == &element)
+            .map(|i| self.coord_of(i))
+    }
+}
+
+impl<T: Display> Board<T> {
     pub fn print_board(&self) {
-        for row in &self.rows {
-            let line: String = row
+        println!("{}", self);
+    }
+}
+
+impl<T: Tile> Board<T> {
+    /// Prints each cell as its tile character, the layout `read_board(_, Tiles)` reads.
+    pub fn print_tiles(&self) {
+        println!("{}", self.map(Tile::to_char));
+    }
+}
+
+impl<T: Display> Display for Board<T> {
+    /// Cells are written back to back when each renders as one character.
+    /// Otherwise every cell, including the first of a row, is preceded by a
+    /// space and right-aligned to a common width.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let widest = self
+            .cells
+            .iter()
+            .map(|cell| cell.to_string().chars().count())
+            .max()
+            .unwrap_or(0);
+        if widest <= 1 {
+            return write!(f, "{}", BoardFormat::default().display(self));
+        }
+        let format = BoardFormat::new(" ", widest);
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}{:>width$}", format.separator, cell, width = widest)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: FromStr> FromStr for Board<T> {
+    type Err = BoardParseError;
+
+    /// Reads one cell per character, the layout `Display` uses for boards of
+    /// single-character cells. Text written in the separated layout is read
+    /// with `BoardFormat::new(" ", 0).parse`; its leading spaces make it fail
+    /// here instead of being misread.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BoardFormat::default().parse(s)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellChange<T> {
+    pub coord: Coord,
+    pub old: T,
+    pub new: T,
+}
+
+impl<T: PartialEq + Clone> Board<T> {
+    /// Cells that differ between `self` and `other`, in row-major order.
+    pub fn diff(&self, other: &Board<T>) -> Vec<CellChange<T>> {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "cannot diff boards of different sizes"
+        );
+        self.iter()
+            .zip(other.cells.iter())
+            .filter(|((_, old), new)| old != new)
+            .map(|((coord, old), new)| CellChange {
+                coord,
+                old: old.clone(),
+                new: new.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Text layout of a board: what goes between cells and how wide each cell is padded.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BoardFormat {
+    pub separator: String,
+    pub cell_width: usize,
+}
+
+impl BoardFormat {
+    pub fn new(separator: &str, cell_width: usize) -> Self {
+        Self {
+            separator: separator.to_string(),
+            cell_width,
+        }
+    }
+
+    pub fn display<'a, T: Display>(&'a self, board: &'a Board<T>) -> BoardDisplay<'a, T> {
+        BoardDisplay {
+            board,
+            format: self,
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self, input: &str) -> Result<Board<T>, BoardParseError> {
+        Board::parse_rows(input.lines(), |line, row| {
+            self.split_row(row)
+                .into_iter()
+                .map(|(column, value)| {
+                    value.parse().map_err(|_| BoardParseError::InvalidValue {
+                        line,
+                        column,
+                        value: value.to_string(),
+                    })
+                })
+                .collect()
+        })
+    }
+
+    /// Cell texts of a row with the 1-based column each one starts at.
+    fn split_row<'a>(&self, row: &'a str) -> Vec<(usize, &'a str)> {
+        let column = |offset: usize| row[..offset].chars().count() + 1;
+        if self.separator.is_empty() {
+            let starts: Vec<usize> = row
+                .char_indices()
+                .map(|(i, _)| i)
+                .step_by(self.cell_width.max(1))
+                .collect();
+            starts
                 .iter()
-                .map(|cell| format!("{}", cell))
-                .collect::<Vec<_>>()
-                .join("");
-            println!("{}", line);
+                .enumerate()
+                .map(|(i, &start)| {
+                    let end = starts.get(i + 1).copied().unwrap_or(row.len());
+                    // Only padded cells are trimmed, so a single-character cell may be a space.
+                    let value = &row[start..end];
+                    let value = if self.cell_width > 1 {
+                        value.trim()
+                    } else {
+                        value
+                    };
+                    (column(start), value)
+                })
+                .collect()
+        } else if self.separator.trim().is_empty() {
+            let mut cells = Vec::new();
+            let mut start = None;
+            for (i, c) in row.char_indices().chain(std::iter::once((row.len(), ' '))) {
+                match (start, c.is_whitespace()) {
+                    (None, false) => start = Some(i),
+                    (Some(s), true) => {
+                        cells.push((column(s), &row[s..i]));
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+            cells
+        } else {
+            let mut offset = 0;
+            row.split(self.separator.as_str())
+                .map(|value| {
+                    let start = offset + (value.len() - value.trim_start().len());
+                    offset += value.len() + self.separator.len();
+                    (column(start), value.trim())
+                })
+                .collect()
+        }
+    }
+}
+
+pub struct BoardDisplay<'a, T> {
+    board: &'a Board<T>,
+    format: &'a BoardFormat,
+}
+
+impl<T: Display> Display for BoardDisplay<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.board.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for (x, cell) in row.iter().enumerate() {
+                if x > 0 {
+                    write!(f, "{}", self.format.separator)?;
+                }
+                write!(f, "{:>width$}", cell, width = self.format.cell_width)?;
+            }
         }
+        Ok(())
+    }
+}
+
+/// Two-way mapping between a cell type and the character it is drawn with.
+pub trait Tile: Sized {
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl Tile for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// Turns one character of board input into a cell. Closures over `&char`
+/// are parsers, and `Tiles` parses any `Tile` type.
+pub trait CellParser<T> {
+    fn parse_cell(&self, c: char) -> Option<T>;
+}
+
+impl<T, F: Fn(&char) -> Option<T>> CellParser<T> for F {
+    fn parse_cell(&self, c: char) -> Option<T> {
+        self(&c)
+    }
+}
+
+/// Parses cells with `Tile::from_char`, e.g. `Board::<MyTile>::read_board(path, Tiles)`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Tiles;
+
+impl<T: Tile> CellParser<T> for Tiles {
+    fn parse_cell(&self, c: char) -> Option<T> {
+        T::from_char(c)
+    }
+}
+
+#[derive(Debug)]
+pub struct BoardView<'a, T> {
+    board: &'a Board<T>,
+    origin: Coord,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Clone for BoardView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BoardView<'_, T> {}
+
+impl<'a, T> BoardView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Position of the view's top-left cell in the underlying board.
+    pub fn origin(&self) -> Coord {
+        self.origin
+    }
+
+    pub fn is_in_bound(&self, coord: Coord) -> bool {
+        coord.x >= 0
+            && coord.y >= 0
+            && (coord.x as usize) < self.width
+            && (coord.y as usize) < self.height
+    }
+
+    pub fn get_value(&self, coord: Coord) -> Option<&'a T> {
+        if self.is_in_bound(coord) {
+            self.board.get_value(self.origin + coord)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &'a [T] {
+        assert!(y < self.height, "row {} out of bounds", y);
+        let x = self.origin.x as usize;
+        &self.board.row(self.origin.y as usize + y)[x..x + self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+        let view = *self;
+        (0..self.height).map(move |y| view.row(y))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &'a T)> + 'a {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, cell)| (Coord::new(x as i32, y as i32), cell))
+        })
+    }
+
+    pub fn sub_view(&self, top_left: Coord, bottom_right: Coord) -> Option<BoardView<'a, T>> {
+        if !self.is_in_bound(top_left)
+            || !self.is_in_bound(bottom_right)
+            || top_left.x > bottom_right.x
+            || top_left.y > bottom_right.y
+        {
+            return None;
+        }
+        Some(BoardView {
+            board: self.board,
+            origin: self.origin + top_left,
+            width: (bottom_right.x - top_left.x + 1) as usize,
+            height: (bottom_right.y - top_left.y + 1) as usize,
+        })
+    }
+
+    pub fn to_board(&self) -> Board<T>
+    where
+        T: Clone,
+    {
+        let cells = self.rows().flat_map(|row| row.iter().cloned()).collect();
+        Board::from_vec(self.width, self.height, cells)
+    }
+}
+
+impl<T> Index<Coord> for BoardView<'_, T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get_value(coord)
+            .unwrap_or_else(|| panic!("{:?} is out of bounds", coord))
+    }
+}
+
+impl<T> Grid for BoardView<'_, T> {
+    type Cell = T;
+
+    fn get_value(&self, coord: Coord) -> Option<&T> {
+        BoardView::get_value(self, coord)
     }
 }
 
 impl Board<char> {
-    pub fn read_char_board(path: &str) -> Self {
-        Self::read_board(path, &|c| *c)
+    pub fn read_char_board(path: &str) -> Result<Self, BoardParseError> {
+        Self::read_board(path, Tiles)
     }
 
-    pub fn from_string(p0: &str) -> Self {
-        let rows: Vec<Vec<char>> = p0
-            .lines()
-            .map(|line| line.chars().map(|c| c.into()).collect())
-            .collect();
-        Self { rows }
+    pub fn from_string(input: &str) -> Result<Self, BoardParseError> {
+        Self::parse_board(input, Tiles)
     }
 
     pub fn empty(width: usize, height: usize) -> Self {
@@ -87,8 +860,16 @@ impl Board<char> {
 }
 
 impl Board<i32> {
-    pub fn read_int_board(path: &str) -> Self {
-        Self::read_board(path, &|c| c.to_digit(10).unwrap() as i32)
+    pub fn read_int_board(path: &str) -> Result<Self, BoardParseError> {
+        Self::read_board(path, Self::parse_digit)
+    }
+
+    pub fn parse_int_board(input: &str) -> Result<Self, BoardParseError> {
+        Self::parse_board(input, Self::parse_digit)
+    }
+
+    fn parse_digit(c: &char) -> Option<i32> {
+        c.to_digit(10).map(|d| d as i32)
     }
 
     pub fn empty(width: usize, height: usize) -> Self {
@@ -96,54 +877,487 @@ impl Board<i32> {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-pub struct Coord {
-    pub x: i32,
-    pub y: i32,
+#[derive(Debug)]
+pub enum BoardParseError {
+    Io(io::Error),
+    Empty,
+    MissingSection(String),
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    BadIndent {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    InvalidCell {
+        line: usize,
+        column: usize,
+        character: char,
+    },
+    InvalidValue {
+        line: usize,
+        column: usize,
+        value: String,
+    },
 }
 
-impl Coord {
-    pub fn new(x: i32, y: i32) -> Coord {
-        Coord { x, y }
+impl Display for BoardParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BoardParseError::Io(err) => write!(f, "failed to read board: {}", err),
+            BoardParseError::Empty => write!(f, "board input is empty"),
+            BoardParseError::MissingSection(name) => write!(f, "no section named {:?}", name),
+            BoardParseError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} cells but found {}",
+                line, expected, found
+            ),
+            BoardParseError::BadIndent {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected an indent of {} but found {}",
+                line, expected, found
+            ),
+            BoardParseError::InvalidCell {
+                line,
+                column,
+                character,
+            } => write!(
+                f,
+                "line {}, column {}: unexpected character {:?}",
+                line, column, character
+            ),
+            BoardParseError::InvalidValue {
+                line,
+                column,
+                value,
+            } => write!(
+                f,
+                "line {}, column {}: cannot parse {:?}",
+                line, column, value
+            ),
+        }
     }
+}
 
-    pub fn up(&self) -> Coord {
-        Coord::new(self.x, self.y - 1)
+impl Error for BoardParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BoardParseError::Io(err) => Some(err),
+            _ => None,
+        }
     }
+}
 
-    pub fn down(&self) -> Coord {
-        Coord::new(self.x, self.y + 1)
+impl From<io::Error> for BoardParseError {
+    fn from(err: io::Error) -> Self {
+        BoardParseError::Io(err)
     }
+}
 
-    pub fn left(&self) -> Coord {
-        Coord::new(self.x - 1, self.y)
-    }
+#[derive(Debug)]
+pub struct Column<'a, T> {
+    board: &'a Board<T>,
+    x: usize,
+}
 
-    pub fn right(&self) -> Coord {
-        Coord::new(self.x + 1, self.y)
+impl<T> Clone for Column<'_, T> {
+    fn clone(&self) -> Self {
+        *self
     }
 }
 
-impl Add for Coord {
-    type Output = Self;
+impl<T> Copy for Column<'_, T> {}
+
+impl<'a, T> Column<'a, T> {
+    pub fn len(&self) -> usize {
+        self.board.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.board.height == 0
+    }
+
+    pub fn get(&self, y: usize) -> Option<&'a T> {
+        if y < self.board.height {
+            Some(&self.board.cells[y * self.board.width + self.x])
+        } else {
+            None
+        }
+    }
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        self.into_iter()
     }
 }
 
-impl Mul for Coord {
-    type Output = Self;
+impl<'a, T> IntoIterator for Column<'a, T> {
+    type Item = &'a T;
+    type IntoIter = std::iter::StepBy<std::slice::Iter<'a, T>>;
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self::new(self.x * rhs.x, self.y * rhs.y)
+    fn into_iter(self) -> Self::IntoIter {
+        let cells = self.board.cells.get(self.x..).unwrap_or(&[]);
+        cells.iter().step_by(self.board.width.max(1))
     }
 }
 
-impl Sub for Coord {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
+impl<T> Index<usize> for Column<'_, T> {
+    type Output = T;
+
+    fn index(&self, y: usize) -> &Self::Output {
+        self.get(y).expect("row out of bounds")
     }
 }
 
+pub const NEIGHBORS_4: [Coord; 4] = [
+    Coord { x: 0, y: -1 },
+    Coord { x: 1, y: 0 },
+    Coord { x: 0, y: 1 },
+    Coord { x: -1, y: 0 },
+];
+
+pub const NEIGHBORS_8: [Coord; 8] = [
+    Coord { x: 0, y: -1 },
+    Coord { x: 1, y: -1 },
+    Coord { x: 1, y: 0 },
+    Coord { x: 1, y: 1 },
+    Coord { x: 0, y: 1 },
+    Coord { x: -1, y: 1 },
+    Coord { x: -1, y: 0 },
+    Coord { x: -1, y: -1 },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flat_storage() {
+        let board = Board::from_string("abc\ndef").unwrap();
+        assert_eq!((board.width(), board.height()), (3, 2));
+        assert_eq!(
+            board.rect(),
+            Some(Rect::new(Coord::new(0, 0), Coord::new(2, 1)))
+        );
+        assert_eq!(Board::<i32>::new(vec![]).rect(), None);
+        assert_eq!(board.cells(), &['a', 'b', 'c', 'd', 'e', 'f']);
+        assert_eq!(board.row(1), &['d', 'e', 'f']);
+        assert_eq!(board.column(2).iter().collect::<String>(), "cf");
+        assert_eq!(board.column(0)[1], 'd');
+    }
+
+    #[test]
+    fn test_get_set_and_find() {
+        let mut board = Board::<char>::empty(4, 3);
+        board.set_value(Coord::new(3, 1), '#');
+        assert_eq!(board.get_value(Coord::new(3, 1)), Some(&'#'));
+        assert_eq!(board.get_value(Coord::new(4, 1)), None);
+        assert_eq!(board.get_value(Coord::new(-1, 0)), None);
+        assert_eq!(board.find_element('#'), Some(Coord::new(3, 1)));
+        assert!(!board.is_in_bound(Coord::new(0, 3)));
+    }
+
+    fn parse(input: &str) -> Result<Board<i32>, BoardParseError> {
+        Board::parse_int_board(input)
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("123\n456\n\n").unwrap().height(), 2);
+        assert_eq!(parse("\n\n123\n456").unwrap(), parse("123\n456").unwrap());
+        assert!(matches!(parse("\n\n"), Err(BoardParseError::Empty)));
+        assert!(matches!(
+            parse("\n123\n45"),
+            Err(BoardParseError::RaggedRow {
+                line: 3,
+                expected: 3,
+                found: 2
+            })
+        ));
+        assert!(matches!(parse(""), Err(BoardParseError::Empty)));
+        assert!(matches!(
+            parse("123\n45\n"),
+            Err(BoardParseError::RaggedRow {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        ));
+        assert!(matches!(
+            Board::from_string("ab\nc"),
+            Err(BoardParseError::RaggedRow { line: 2, .. })
+        ));
+        let err = parse("123\n4x6").unwrap_err();
+        assert!(matches!(
+            err,
+            BoardParseError::InvalidCell {
+                line: 2,
+                column: 2,
+                character: 'x'
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: unexpected character 'x'"
+        );
+        assert!(matches!(
+            Board::read_char_board("does/not/exist.txt"),
+            Err(BoardParseError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_iteration() {
+        let mut board = Board::from_string("ab\ncd\nef").unwrap();
+        let coords: Vec<(Coord, char)> = board.iter().map(|(c, v)| (c, *v)).collect();
+        assert_eq!(coords[3], (Coord::new(1, 1), 'd'));
+        assert_eq!(coords.len(), 6);
+        for (coord, cell) in board.iter_mut() {
+            if coord.x == 1 {
+                *cell = cell.to_ascii_uppercase();
+            }
+        }
+        assert_eq!(
+            board.rows().collect::<Vec<_>>(),
+            [['a', 'B'], ['c', 'D'], ['e', 'F']]
+        );
+        let columns: Vec<String> = board.columns().map(|c| c.iter().collect()).collect();
+        assert_eq!(columns, ["ace", "BDF"]);
+        board[Coord::new(0, 2)] = 'z';
+        assert_eq!(board[Coord::new(0, 2)], 'z');
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let board = Board::from_string("abc\ndef\nghi").unwrap();
+        let corner: String = board.neighbors4(Coord::new(0, 0)).map(|(_, v)| v).collect();
+        assert_eq!(corner, "bd");
+        let center: String = board.neighbors8(Coord::new(1, 1)).map(|(_, v)| v).collect();
+        assert_eq!(center, "bcfihgda");
+        let edge: Vec<Coord> = board.neighbors8(Coord::new(2, 1)).map(|(c, _)| c).collect();
+        assert_eq!(edge.len(), 5);
+    }
+
+    #[test]
+    fn test_toroidal() {
+        let mut board = Board::from_string("abc\ndef")
+            .unwrap()
+            .with_topology(Topology::Toroidal);
+        assert_eq!(board.get_value(Coord::new(-1, 0)), Some(&'c'));
+        assert_eq!(board.get_value(Coord::new(4, 3)), Some(&'e'));
+        assert!(board.is_in_bound(Coord::new(-7, 12)));
+        assert_eq!(board.wrap(Coord::new(-7, 12)), Coord::new(2, 0));
+        board.set_value(Coord::new(3, -1), 'z');
+        assert_eq!(board[Coord::new(0, 1)], 'z');
+        let corner: Vec<Coord> = board.neighbors4(Coord::new(0, 0)).map(|(c, _)| c).collect();
+        assert_eq!(
+            corner,
+            [
+                Coord::new(0, 1),
+                Coord::new(1, 0),
+                Coord::new(0, 1),
+                Coord::new(2, 0)
+            ]
+        );
+        board.set_topology(Topology::Bounded);
+        assert_eq!(board.get_value(Coord::new(-1, 0)), None);
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Terrain {
+        Open,
+        Tree,
+    }
+
+    impl Tile for Terrain {
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '.' => Some(Terrain::Open),
+                '#' => Some(Terrain::Tree),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Terrain::Open => '.',
+                Terrain::Tree => '#',
+            }
+        }
+    }
+
+    #[test]
+    fn test_tile_round_trip() {
+        let board = Board::<Terrain>::parse_board("..#\n#..", Tiles).unwrap();
+        assert_eq!(board[Coord::new(2, 0)], Terrain::Tree);
+        assert_eq!(board.find_element(Terrain::Tree), Some(Coord::new(2, 0)));
+        assert_eq!(board.map(Tile::to_char).to_string(), "..#\n#..");
+        assert!(matches!(
+            Board::<Terrain>::parse_board("..#\n#.x", Tiles),
+            Err(BoardParseError::InvalidCell {
+                line: 2,
+                column: 3,
+                character: 'x'
+            })
+        ));
+    }
+
+    #[test]
+    fn test_transforms() {
+        let board = Board::from_string("abc\ndef").unwrap();
+        let text = |b: Board<char>| {
+            b.rows()
+                .map(|r| r.iter().collect())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(text(board.rotate_90()), ["da", "eb", "fc"]);
+        assert_eq!(text(board.rotate_180()), ["fed", "cba"]);
+        assert_eq!(text(board.rotate_270()), ["cf", "be", "ad"]);
+        assert_eq!(text(board.transpose()), ["ad", "be", "cf"]);
+        assert_eq!(text(board.flip_horizontal()), ["cba", "fed"]);
+        assert_eq!(text(board.flip_vertical()), ["def", "abc"]);
+        assert_eq!(board.rotate_90().rotate_270(), board);
+        assert_eq!(
+            text(board.crop(Coord::new(1, 0), Coord::new(2, 1)).unwrap()),
+            ["bc", "ef"]
+        );
+        assert!(board.crop(Coord::new(1, 0), Coord::new(3, 1)).is_none());
+    }
+
+    #[test]
+    fn test_expand() {
+        let board = Board::from_string("#O\n.@").unwrap();
+        let (wide, expansion) = board.expand(2, 1, |c| match c {
+            'O' => vec!['[', ']'],
+            '@' => vec!['@', '.'],
+            other => vec![*other, *other],
+        });
+        assert_eq!(wide.to_string(), "##[]\n..@.");
+        assert_eq!(expansion.to_expanded(Coord::new(1, 1)), Coord::new(2, 1));
+        assert_eq!(expansion.to_original(Coord::new(3, 0)), Coord::new(1, 0));
+
+        let (big, expansion) = board.expand(2, 3, |c| vec![*c as u32; 6]);
+        assert_eq!((big.width(), big.height()), (4, 6));
+        assert_eq!(big[Coord::new(3, 5)], '@' as u32);
+        assert_eq!(big[Coord::new(1, 3)], '.' as u32);
+        assert_eq!(expansion.to_original(Coord::new(-1, 7)), Coord::new(-1, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "are empty")]
+    fn test_expand_rejects_empty_blocks() {
+        Board::from_string("ab")
+            .unwrap()
+            .expand(0, 1, |_| Vec::<char>::new());
+    }
+
+    #[test]
+    fn test_sub_view() {
+        let board = Board::from_string("abcd\nefgh\nijkl").unwrap();
+        let view = board.sub_view(Coord::new(1, 1), Coord::new(3, 2)).unwrap();
+        assert_eq!((view.width(), view.height()), (3, 2));
+        assert_eq!(view.row(1), &['j', 'k', 'l']);
+        assert_eq!(view[Coord::new(0, 0)], 'f');
+        assert_eq!(view.get_value(Coord::new(3, 0)), None);
+        let inner = view.sub_view(Coord::new(1, 0), Coord::new(1, 1)).unwrap();
+        assert_eq!(inner.origin(), Coord::new(2, 1));
+        assert_eq!(inner.iter().map(|(_, v)| v).collect::<String>(), "gk");
+    }
+
+    #[test]
+    fn test_other_sources() {
+        let reader = io::Cursor::new("12\n34\n");
+        let board = Board::from_reader(reader, |c: &char| c.to_digit(10)).unwrap();
+        assert_eq!(board.row(1), &[3, 4]);
+
+        let document = "Map:\n#.\n.#\n\nMoves:\n<>\n";
+        let board = Board::<char>::from_section(document, "Map", Tiles).unwrap();
+        assert_eq!(board.map(Tile::to_char).to_string(), "#.\n.#");
+        assert!(matches!(
+            Board::<char>::from_section(document, "Heights", Tiles),
+            Err(BoardParseError::MissingSection(name)) if name == "Heights"
+        ));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let chars = Board::from_string("#.\n.@").unwrap();
+        assert_eq!(chars.to_string(), "#.\n.@");
+        assert_eq!(chars.to_string().parse::<Board<char>>().unwrap(), chars);
+
+        let ints = Board::new(vec![vec![1, 12], vec![-3, 4]]);
+        assert_eq!(ints.to_string(), "  1 12\n -3  4");
+        ints.print_board();
+        let separated = BoardFormat::new(" ", 0);
+        assert_eq!(separated.parse::<i32>(&ints.to_string()).unwrap(), ints);
+        assert!(ints.to_string().parse::<Board<i32>>().is_err());
+
+        let column = Board::new(vec![vec![12], vec![34]]);
+        assert_eq!(column.to_string(), " 12\n 34");
+        assert_eq!(separated.parse::<i32>(&column.to_string()).unwrap(), column);
+        assert!(column.to_string().parse::<Board<i32>>().is_err());
+
+        let spaced = Board::from_string("a b\nc d").unwrap();
+        assert_eq!(spaced.width(), 3);
+        assert_eq!(spaced.to_string(), "a b\nc d");
+        assert_eq!(spaced.to_string().parse::<Board<char>>().unwrap(), spaced);
+
+        let blank_edge = Board::from_string(" a\n b").unwrap();
+        assert_eq!(blank_edge.width(), 2);
+        assert_eq!(blank_edge.to_string(), " a\n b");
+        assert_eq!(
+            blank_edge.to_string().parse::<Board<char>>().unwrap(),
+            blank_edge
+        );
+
+        let csv = BoardFormat::new(",", 3);
+        assert_eq!(csv.display(&ints).to_string(), "  1, 12\n -3,  4");
+        assert_eq!(csv.parse::<i32>("  1, 12\n -3,  4").unwrap(), ints);
+        assert_eq!(
+            BoardFormat::new("", 2).parse::<i32>(" 112\n-3 4").unwrap(),
+            ints
+        );
+        assert!(matches!(
+            csv.parse::<i32>("1,2\n3, x"),
+            Err(BoardParseError::InvalidValue { line: 2, column: 4, value }) if value == "x"
+        ));
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = Board::from_string("#..\n.O.").unwrap();
+        let after = Board::from_string("#..\n..O").unwrap();
+        assert_eq!(
+            before.diff(&after),
+            [
+                CellChange {
+                    coord: Coord::new(1, 1),
+                    old: 'O',
+                    new: '.'
+                },
+                CellChange {
+                    coord: Coord::new(2, 1),
+                    old: '.',
+                    new: 'O'
+                }
+            ]
+        );
+        assert!(after.diff(&after).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ragged_rows() {
+        Board::new(vec![vec![1, 2], vec![3]]);
+    }
+}
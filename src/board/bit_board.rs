@@ -0,0 +1,398 @@
+use super::{Board, Coord, Grid};
+use std::fmt::{Display, Formatter};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A set of cells on a `width` x `height` grid, packed one bit per cell.
+/// Each row starts on a fresh word so rows can be shifted independently.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitBoard {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+impl BitBoard {
+    pub fn new(width: usize, height: usize) -> Self {
+        let stride = width.div_ceil(WORD_BITS);
+        Self {
+            words: vec![0; stride * height],
+            width,
+            height,
+            stride,
+        }
+    }
+
+    /// Cells of `board` whose value satisfies `predicate`.
+    pub fn from_board<T>(board: &Board<T>, predicate: impl Fn(&T) -> bool) -> Self {
+        let mut bits = Self::new(board.width(), board.height());
+        for (coord, value) in board.iter() {
+            if predicate(value) {
+                bits.set(coord);
+            }
+        }
+        bits
+    }
+
+    pub fn from_coords(
+        width: usize,
+        height: usize,
+        coords: impl IntoIterator<Item = Coord>,
+    ) -> Self {
+        let mut bits = Self::new(width, height);
+        for coord in coords {
+            bits.set(coord);
+        }
+        bits
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_in_bound(&self, coord: Coord) -> bool {
+        coord.x >= 0
+            && coord.y >= 0
+            && (coord.x as usize) < self.width
+            && (coord.y as usize) < self.height
+    }
+
+    fn locate(&self, coord: Coord) -> Option<(usize, u64)> {
+        if !self.is_in_bound(coord) {
+            return None;
+        }
+        let (x, y) = (coord.x as usize, coord.y as usize);
+        Some((y * self.stride + x / WORD_BITS, 1 << (x % WORD_BITS)))
+    }
+
+    fn locate_or_panic(&self, coord: Coord) -> (usize, u64) {
+        self.locate(coord)
+            .unwrap_or_else(|| panic!("{:?} is out of bounds", coord))
+    }
+
+    /// Whether the cell is set; cells outside the board never are.
+    pub fn test(&self, coord: Coord) -> bool {
+        self.locate(coord)
+            .is_some_and(|(word, bit)| self.words[word] & bit != 0)
+    }
+
+    /// Sets the cell and returns whether it was previously clear.
+    pub fn set(&mut self, coord: Coord) -> bool {
+        let (word, bit) = self.locate_or_panic(coord);
+        let was_clear = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        was_clear
+    }
+
+    /// Clears the cell and returns whether it was previously set.
+    pub fn clear(&mut self, coord: Coord) -> bool {
+        let (word, bit) = self.locate_or_panic(coord);
+        let was_set = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        was_set
+    }
+
+    pub fn clear_all(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    /// Set cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, word)| {
+            let y = (i / self.stride) as i32;
+            let base = (i % self.stride) * WORD_BITS;
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(Coord::new((base + bit) as i32, y))
+            })
+        })
+    }
+
+    /// Cells not in the set.
+    pub fn complement(&self) -> Self {
+        let mut result = self.clone();
+        for word in &mut result.words {
+            *word = !*word;
+        }
+        result.mask_padding();
+        result
+    }
+
+    /// The set moved by `offset`; cells pushed past an edge are dropped.
+    pub fn shift(&self, offset: Coord) -> Self {
+        let mut result = Self::new(self.width, self.height);
+        for y in 0..self.height {
+            let source = y as i64 - offset.y as i64;
+            if source < 0 || source >= self.height as i64 {
+                continue;
+            }
+            let source = source as usize * self.stride;
+            let target = y * self.stride;
+            shift_row(
+                &self.words[source..source + self.stride],
+                &mut result.words[target..target + self.stride],
+                offset.x as i64,
+            );
+        }
+        result.mask_padding();
+        result
+    }
+
+    /// Cells reachable from some set cell by one of `deltas`.
+    pub fn dilate(&self, deltas: &[Coord]) -> Self {
+        let mut result = Self::new(self.width, self.height);
+        for delta in deltas {
+            result |= &self.shift(*delta);
+        }
+        result
+    }
+
+    fn mask_padding(&mut self) {
+        let used = self.width % WORD_BITS;
+        if used == 0 {
+            return;
+        }
+        let mask = (1u64 << used) - 1;
+        for row in self.words.chunks_mut(self.stride) {
+            if let Some(last) = row.last_mut() {
+                *last &= mask;
+            }
+        }
+    }
+
+    fn assert_same_size(&self, other: &Self) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "bit boards must have the same size"
+        );
+    }
+
+    fn zip_with(&mut self, other: &Self, op: impl Fn(u64, u64) -> u64) {
+        self.assert_same_size(other);
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word = op(*word, *other);
+        }
+    }
+}
+
+/// Moves the bits of one row `dx` cells to the right (left when negative).
+fn shift_row(source: &[u64], target: &mut [u64], dx: i64) {
+    let words = (dx.unsigned_abs() as usize) / WORD_BITS;
+    let bits = (dx.unsigned_abs() as usize) % WORD_BITS;
+    let len = source.len() as i64;
+    let get = |i: i64| {
+        if i < 0 || i >= len {
+            0
+        } else {
+            source[i as usize]
+        }
+    };
+    for (i, word) in target.iter_mut().enumerate() {
+        let i = i as i64;
+        *word = if dx >= 0 {
+            let from = i - words as i64;
+            let carry = if bits == 0 {
+                0
+            } else {
+                get(from - 1) >> (WORD_BITS - bits)
+            };
+            get(from) << bits | carry
+        } else {
+            let from = i + words as i64;
+            let carry = if bits == 0 {
+                0
+            } else {
+                get(from + 1) << (WORD_BITS - bits)
+            };
+            get(from) >> bits | carry
+        };
+    }
+}
+
+impl Grid for BitBoard {
+    type Cell = bool;
+
+    fn get_value(&self, coord: Coord) -> Option<&bool> {
+        self.locate(coord).map(|(word, bit)| {
+            if self.words[word] & bit != 0 {
+                &true
+            } else {
+                &false
+            }
+        })
+    }
+
+    fn is_in_bound(&self, coord: Coord) -> bool {
+        BitBoard::is_in_bound(self, coord)
+    }
+}
+
+impl BitOrAssign<&BitBoard> for BitBoard {
+    fn bitor_assign(&mut self, other: &BitBoard) {
+        self.zip_with(other, |a, b| a | b);
+    }
+}
+
+impl BitAndAssign<&BitBoard> for BitBoard {
+    fn bitand_assign(&mut self, other: &BitBoard) {
+        self.zip_with(other, |a, b| a & b);
+    }
+}
+
+impl SubAssign<&BitBoard> for BitBoard {
+    fn sub_assign(&mut self, other: &BitBoard) {
+        self.zip_with(other, |a, b| a & !b);
+    }
+}
+
+impl BitOr for &BitBoard {
+    type Output = BitBoard;
+
+    fn bitor(self, other: &BitBoard) -> BitBoard {
+        let mut result = self.clone();
+        result |= other;
+        result
+    }
+}
+
+impl BitAnd for &BitBoard {
+    type Output = BitBoard;
+
+    fn bitand(self, other: &BitBoard) -> BitBoard {
+        let mut result = self.clone();
+        result &= other;
+        result
+    }
+}
+
+impl Sub for &BitBoard {
+    type Output = BitBoard;
+
+    fn sub(self, other: &BitBoard) -> BitBoard {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl Display for BitBoard {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for x in 0..self.width {
+                let set = self.test(Coord::new(x as i32, y as i32));
+                write!(f, "{}", if set { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::{NEIGHBORS_4, NEIGHBORS_8};
+
+    fn bits(input: &str) -> BitBoard {
+        BitBoard::from_board(&Board::from_string(input).unwrap(), |c| *c == '#')
+    }
+
+    #[test]
+    fn test_set_clear_test() {
+        let mut board = BitBoard::new(70, 2);
+        assert!(board.set(Coord::new(65, 1)));
+        assert!(!board.set(Coord::new(65, 1)));
+        assert!(board.set(Coord::new(0, 0)));
+        assert!(board.test(Coord::new(65, 1)));
+        assert!(!board.test(Coord::new(65, 0)));
+        assert!(!board.test(Coord::new(-1, 0)));
+        assert_eq!(board.count_ones(), 2);
+        assert_eq!(
+            board.iter().collect::<Vec<_>>(),
+            vec![Coord::new(0, 0), Coord::new(65, 1)]
+        );
+        assert!(board.clear(Coord::new(0, 0)));
+        assert!(!board.clear(Coord::new(0, 0)));
+        assert_eq!(board.count_ones(), 1);
+        board.clear_all();
+        assert!(board.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_set_out_of_bounds() {
+        BitBoard::new(3, 3).set(Coord::new(3, 0));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = bits("##.\n...");
+        let b = bits(".##\n..#");
+        assert_eq!((&a | &b).to_string(), "###\n..#");
+        assert_eq!((&a & &b).to_string(), ".#.\n...");
+        assert_eq!((&a - &b).to_string(), "#..\n...");
+        assert_eq!(a.complement().to_string(), "..#\n###");
+        assert_eq!(a.complement().count_ones(), 4);
+    }
+
+    #[test]
+    fn test_shift_and_dilate() {
+        let board = bits("#..\n.#.\n...");
+        assert_eq!(board.shift(Coord::new(1, 0)).to_string(), ".#.\n..#\n...");
+        assert_eq!(board.shift(Coord::new(-1, 1)).to_string(), "...\n...\n#..");
+        assert_eq!(board.shift(Coord::new(0, -1)).to_string(), ".#.\n...\n...");
+
+        let center = BitBoard::from_coords(3, 3, [Coord::new(1, 1)]);
+        assert_eq!(center.dilate(&NEIGHBORS_4).to_string(), ".#.\n#.#\n.#.");
+        assert_eq!(center.dilate(&NEIGHBORS_8).count_ones(), 8);
+    }
+
+    #[test]
+    fn test_shift_across_words() {
+        let mut board = BitBoard::new(130, 1);
+        board.set(Coord::new(62, 0));
+        board.set(Coord::new(129, 0));
+        let right = board.shift(Coord::new(3, 0));
+        assert_eq!(right.iter().collect::<Vec<_>>(), vec![Coord::new(65, 0)]);
+        let left = board.shift(Coord::new(-66, 0));
+        assert_eq!(left.iter().collect::<Vec<_>>(), vec![Coord::new(63, 0)]);
+        assert_eq!(board.shift(Coord::new(-64, 0)).count_ones(), 1);
+    }
+
+    #[test]
+    fn test_grid_neighbors() {
+        let walls = bits(".#\n..");
+        let open: Vec<Coord> = walls
+            .neighbors4(Coord::new(0, 0))
+            .filter(|(_, wall)| !**wall)
+            .map(|(coord, _)| coord)
+            .collect();
+        assert_eq!(open, vec![Coord::new(0, 1)]);
+        assert_eq!(walls.neighbors(Coord::new(0, 0), &NEIGHBORS_8).count(), 3);
+    }
+}
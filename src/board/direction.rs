@@ -0,0 +1,249 @@
+use super::{Coord, Tile, NEIGHBORS_4, NEIGHBORS_8};
+use crate::coord::Integer;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::Add;
+use std::str::FromStr;
+
+/// One of the four orthogonal directions, in the same order as [`NEIGHBORS_4`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Quarter turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn to_coord(self) -> Coord {
+        NEIGHBORS_4[self as usize]
+    }
+
+    /// Parses an arrow (`^ > v <`) or a compass letter (`N E S W`).
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' => Some(Direction::Up),
+            '>' | 'E' => Some(Direction::Right),
+            'v' | 'S' => Some(Direction::Down),
+            '<' | 'W' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The arrow pointing this way.
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+/// One of the eight compass directions, clockwise from up like [`NEIGHBORS_8`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Eighth turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Eighth turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn to_coord(self) -> Coord {
+        NEIGHBORS_8[self as usize]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl Tile for Direction {
+    fn from_char(c: char) -> Option<Self> {
+        Direction::from_char(c)
+    }
+
+    fn to_char(&self) -> char {
+        Direction::to_char(*self)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl<N: Integer> From<Direction> for Coord<N> {
+    fn from(direction: Direction) -> Self {
+        let delta = direction.to_coord();
+        Coord::new(delta.x.into(), delta.y.into())
+    }
+}
+
+impl<N: Integer> From<Direction8> for Coord<N> {
+    fn from(direction: Direction8) -> Self {
+        let delta = direction.to_coord();
+        Coord::new(delta.x.into(), delta.y.into())
+    }
+}
+
+impl<N: Integer> Add<Direction> for Coord<N> {
+    type Output = Coord<N>;
+
+    fn add(self, direction: Direction) -> Coord<N> {
+        self + Coord::from(direction)
+    }
+}
+
+impl<N: Integer> Add<Direction8> for Coord<N> {
+    type Output = Coord<N>;
+
+    fn add(self, direction: Direction8) -> Coord<N> {
+        self + Coord::from(direction)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDirectionError(pub(crate) String);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "invalid direction '{}'", self.0)
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.trim().chars();
+        match (chars.next().and_then(Direction::from_char), chars.next()) {
+            (Some(direction), None) => Ok(direction),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction8 {
+    type Err = ParseDirectionError;
+
+    /// Accepts compass points (`N`, `NE`, ...) and the four arrows.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "NE" => Ok(Direction8::UpRight),
+            "SE" => Ok(Direction8::DownRight),
+            "SW" => Ok(Direction8::DownLeft),
+            "NW" => Ok(Direction8::UpLeft),
+            other => other.parse::<Direction>().map(Direction8::from),
+        }
+        .map_err(|_| ParseDirectionError(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(
+                direction.opposite().to_coord(),
+                direction.to_coord() * Coord::new(-1, -1)
+            );
+        }
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.opposite(), Direction8::UpLeft);
+        assert!(Direction8::DownLeft.is_diagonal());
+    }
+
+    #[test]
+    fn test_coords_match_neighbor_tables() {
+        let coords: Vec<Coord> = Direction::ALL.iter().map(|d| d.to_coord()).collect();
+        assert_eq!(coords, NEIGHBORS_4);
+        let coords: Vec<Coord> = Direction8::ALL.into_iter().map(Coord::from).collect();
+        assert_eq!(coords, NEIGHBORS_8);
+        for direction in Direction::ALL {
+            assert_eq!(Direction8::from(direction).to_coord(), direction.to_coord());
+        }
+        assert_eq!(Coord::new(2, 2) + Direction::Left, Coord::new(1, 2));
+        assert_eq!(Coord::new(2, 2) + Direction8::DownRight, Coord::new(3, 3));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert_eq!(Direction::from_char('W'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!("E".parse::<Direction>(), Ok(Direction::Right));
+        assert!("NE".parse::<Direction>().is_err());
+        assert_eq!("NE".parse::<Direction8>(), Ok(Direction8::UpRight));
+        assert_eq!("<".parse::<Direction8>(), Ok(Direction8::Left));
+        assert_eq!(
+            "up".parse::<Direction8>().unwrap_err().to_string(),
+            "invalid direction 'up'"
+        );
+        let arrows: String = Direction::ALL.iter().map(|d| d.to_char()).collect();
+        assert_eq!(arrows, "^>v<");
+    }
+}
@@ -0,0 +1,466 @@
+use super::{Board, BoardParseError, Coord, ParseDirectionError, Tile};
+use crate::coord::Integer;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// A neighbor of a pointy-top hex, clockwise from east.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDirection {
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::SouthEast,
+        HexDirection::SouthWest,
+        HexDirection::West,
+        HexDirection::NorthWest,
+        HexDirection::NorthEast,
+    ];
+
+    /// Sixth turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 6]
+    }
+
+    /// Sixth turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 5) % 6]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 3) % 6]
+    }
+
+    pub fn to_hex<N: Integer>(self) -> Hex<N> {
+        let (q, r) = match self {
+            HexDirection::East => (1, 0),
+            HexDirection::SouthEast => (0, 1),
+            HexDirection::SouthWest => (-1, 1),
+            HexDirection::West => (-1, 0),
+            HexDirection::NorthWest => (0, -1),
+            HexDirection::NorthEast => (1, -1),
+        };
+        Hex::new(q.into(), r.into())
+    }
+}
+
+impl FromStr for HexDirection {
+    type Err = ParseDirectionError;
+
+    /// Accepts `e`, `se`, `sw`, `w`, `nw` and `ne`, in either case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "e" => Ok(HexDirection::East),
+            "se" => Ok(HexDirection::SouthEast),
+            "sw" => Ok(HexDirection::SouthWest),
+            "w" => Ok(HexDirection::West),
+            "nw" => Ok(HexDirection::NorthWest),
+            "ne" => Ok(HexDirection::NorthEast),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+/// Axial coordinate of a pointy-top hex; the third cube coordinate is `s = -q - r`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Hex<N = i32> {
+    pub q: N,
+    pub r: N,
+}
+
+impl<N: Integer> Hex<N> {
+    pub fn new(q: N, r: N) -> Hex<N> {
+        Hex { q, r }
+    }
+
+    pub fn from_cube(q: N, r: N, s: N) -> Hex<N> {
+        assert!(
+            q + r + s == N::ZERO,
+            "cube coordinates {:?} do not sum to zero",
+            (q, r, s)
+        );
+        Hex::new(q, r)
+    }
+
+    pub fn s(&self) -> N {
+        -self.q - self.r
+    }
+
+    pub fn neighbor(&self, direction: HexDirection) -> Hex<N> {
+        *self + direction.to_hex()
+    }
+
+    /// The six adjacent hexes, clockwise from east.
+    pub fn neighbors(&self) -> impl Iterator<Item = Hex<N>> {
+        let hex = *self;
+        HexDirection::ALL
+            .into_iter()
+            .map(move |direction| hex.neighbor(direction))
+    }
+
+    /// Number of steps between the two hexes.
+    pub fn distance(&self, other: Hex<N>) -> N {
+        let delta = *self - other;
+        (delta.q.abs() + delta.r.abs() + delta.s().abs()) / N::from(2)
+    }
+
+    /// Rotates a sixth turn clockwise around the origin.
+    pub fn rotate_right(&self) -> Hex<N> {
+        Hex::new(-self.r, -self.s())
+    }
+
+    /// Rotates a sixth turn counter-clockwise around the origin.
+    pub fn rotate_left(&self) -> Hex<N> {
+        Hex::new(-self.s(), -self.q)
+    }
+
+    /// Rotates `turns` sixth turns clockwise around `center`; negative turns go the other way.
+    pub fn rotate_around(&self, center: Hex<N>, turns: i32) -> Hex<N> {
+        (0..turns.rem_euclid(6)).fold(*self - center, |hex, _| hex.rotate_right()) + center
+    }
+
+    /// Hexes exactly `radius` steps away, clockwise from the east corner.
+    pub fn ring(&self, radius: N) -> Vec<Hex<N>> {
+        if radius == N::ZERO {
+            return vec![*self];
+        }
+        let mut ring = Vec::new();
+        let mut hex = *self + HexDirection::East.to_hex() * radius;
+        for side in HexDirection::ALL {
+            let direction = side.turn_right().turn_right();
+            let mut step = N::ZERO;
+            while step < radius {
+                ring.push(hex);
+                hex = hex.neighbor(direction);
+                step = step + N::ONE;
+            }
+        }
+        ring
+    }
+
+    /// This hex followed by every ring out to `radius`.
+    pub fn spiral(&self, radius: N) -> Vec<Hex<N>> {
+        let mut spiral = Vec::new();
+        let mut ring = N::ZERO;
+        while ring <= radius {
+            spiral.extend(self.ring(ring));
+            ring = ring + N::ONE;
+        }
+        spiral
+    }
+}
+
+impl<N: Integer> Add for Hex<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl<N: Integer> Sub for Hex<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl<N: Integer> Mul<N> for Hex<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: N) -> Self::Output {
+        Self::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl<N: Integer> Neg for Hex<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl<N: Integer> Add<HexDirection> for Hex<N> {
+    type Output = Self;
+
+    fn add(self, direction: HexDirection) -> Self::Output {
+        self.neighbor(direction)
+    }
+}
+
+/// Which rows of a pointy-top hex grid are drawn half a cell to the right.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OffsetLayout {
+    #[default]
+    OddRows,
+    EvenRows,
+}
+
+impl OffsetLayout {
+    fn is_shifted(&self, row: i32) -> bool {
+        match self {
+            OffsetLayout::OddRows => row.rem_euclid(2) == 1,
+            OffsetLayout::EvenRows => row.rem_euclid(2) == 0,
+        }
+    }
+
+    /// The `(column, row)` cell holding `hex`.
+    pub fn to_offset(&self, hex: Hex) -> Coord {
+        let shift = match self {
+            OffsetLayout::OddRows => (hex.r - hex.r.rem_euclid(2)) / 2,
+            OffsetLayout::EvenRows => (hex.r + hex.r.rem_euclid(2)) / 2,
+        };
+        Coord::new(hex.q + shift, hex.r)
+    }
+
+    pub fn to_hex(&self, coord: Coord) -> Hex {
+        let shift = match self {
+            OffsetLayout::OddRows => (coord.y - coord.y.rem_euclid(2)) / 2,
+            OffsetLayout::EvenRows => (coord.y + coord.y.rem_euclid(2)) / 2,
+        };
+        Hex::new(coord.x - shift, coord.y)
+    }
+}
+
+/// A rectangle of hexes stored row by row in a [`Board`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HexBoard<T> {
+    board: Board<T>,
+    layout: OffsetLayout,
+}
+
+impl<T> HexBoard<T> {
+    pub fn new(board: Board<T>, layout: OffsetLayout) -> Self {
+        Self { board, layout }
+    }
+
+    pub fn board(&self) -> &Board<T> {
+        &self.board
+    }
+
+    pub fn into_board(self) -> Board<T> {
+        self.board
+    }
+
+    pub fn layout(&self) -> OffsetLayout {
+        self.layout
+    }
+
+    pub fn is_in_bound(&self, hex: Hex) -> bool {
+        self.board.is_in_bound(self.layout.to_offset(hex))
+    }
+
+    pub fn get_value(&self, hex: Hex) -> Option<&T> {
+        self.board.get_value(self.layout.to_offset(hex))
+    }
+
+    pub fn get_value_mut(&mut self, hex: Hex) -> Option<&mut T> {
+        self.board.get_value_mut(self.layout.to_offset(hex))
+    }
+
+    pub fn set_value(&mut self, hex: Hex, value: T) {
+        match self.get_value_mut(hex) {
+            Some(cell) => *cell = value,
+            None => panic!("{:?} is out of bounds", hex),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Hex, &T)> {
+        let layout = self.layout;
+        self.board
+            .iter()
+            .map(move |(coord, value)| (layout.to_hex(coord), value))
+    }
+
+    pub fn neighbors(&self, hex: Hex) -> impl Iterator<Item = (Hex, &T)> {
+        hex.neighbors()
+            .filter_map(move |neighbor| self.get_value(neighbor).map(|value| (neighbor, value)))
+    }
+}
+
+impl<T: Tile> HexBoard<T> {
+    /// Parses rows of cells separated by single spaces, as written by
+    /// `to_tile_string`. Shifted rows must be indented by exactly one space and
+    /// the others not at all. Columns in errors count characters from 1.
+    pub fn parse_tile_board(input: &str, layout: OffsetLayout) -> Result<Self, BoardParseError> {
+        let mut y = 0;
+        let board = Board::parse_rows(input.lines(), |line, row| {
+            let indent = row.chars().take_while(|c| *c == ' ').count();
+            let expected = usize::from(layout.is_shifted(y));
+            y += 1;
+            if indent != expected {
+                return Err(BoardParseError::BadIndent {
+                    line,
+                    expected,
+                    found: indent,
+                });
+            }
+            let mut cells = Vec::new();
+            for (x, c) in row.chars().enumerate().skip(indent) {
+                let invalid = BoardParseError::InvalidCell {
+                    line,
+                    column: x + 1,
+                    character: c,
+                };
+                if (x - indent) % 2 == 1 {
+                    if c != ' ' {
+                        return Err(invalid);
+                    }
+                } else {
+                    cells.push(T::from_char(c).ok_or(invalid)?);
+                }
+            }
+            Ok(cells)
+        })?;
+        Ok(Self::new(board, layout))
+    }
+
+    /// Renders cells separated by spaces, indenting shifted rows by one.
+    pub fn to_tile_string(&self) -> String {
+        self.board
+            .rows()
+            .enumerate()
+            .map(|(y, row)| {
+                let indent = if self.layout.is_shifted(y as i32) {
+                    " "
+                } else {
+                    ""
+                };
+                let cells: Vec<String> =
+                    row.iter().map(|cell| cell.to_char().to_string()).collect();
+                format!("{}{}", indent, cells.join(" "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbors_and_distance() {
+        let origin = Hex::new(0, 0);
+        assert!(origin.neighbors().all(|n| n.distance(origin) == 1));
+        assert_eq!(Hex::new(3, -1).distance(Hex::new(-1, 2)), 4);
+        assert_eq!(Hex::from_cube(1, -3, 2).s(), 2);
+        for direction in HexDirection::ALL {
+            assert_eq!(origin + direction + direction.opposite(), origin);
+            assert_eq!(direction.turn_right().turn_left(), direction);
+        }
+        let end = ["e", "SE", "ne", "e"]
+            .iter()
+            .map(|d| d.parse::<HexDirection>().unwrap())
+            .fold(origin, |hex, d| hex + d);
+        assert_eq!(end, Hex::new(3, 0));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let east = HexDirection::East.to_hex::<i32>();
+        assert_eq!(east.rotate_right(), HexDirection::SouthEast.to_hex());
+        assert_eq!(east.rotate_left(), HexDirection::NorthEast.to_hex());
+        let hex = Hex::new(2, -1);
+        assert_eq!(hex.rotate_right().rotate_left(), hex);
+        assert_eq!(hex.rotate_around(hex, 3), hex);
+        let center = Hex::new(5, 5);
+        assert_eq!(
+            (center + east).rotate_around(center, -1),
+            center + HexDirection::NorthEast.to_hex()
+        );
+        assert_eq!((center + east).rotate_around(center, 6), center + east);
+    }
+
+    #[test]
+    fn test_rings_and_spirals() {
+        let center = Hex::new(1, 1);
+        assert_eq!(center.ring(0), vec![center]);
+        let ring = center.ring(2);
+        assert_eq!(ring.len(), 12);
+        assert_eq!(ring[0], center + HexDirection::East.to_hex() * 2);
+        assert!(ring.iter().all(|hex| hex.distance(center) == 2));
+        assert_eq!(center.spiral(3).len(), 37);
+        assert_eq!(center.spiral(3)[0], center);
+    }
+
+    #[test]
+    fn test_offset_layouts() {
+        for layout in [OffsetLayout::OddRows, OffsetLayout::EvenRows] {
+            for hex in Hex::new(0, 0).spiral(3) {
+                assert_eq!(layout.to_hex(layout.to_offset(hex)), hex);
+            }
+        }
+        assert_eq!(
+            OffsetLayout::OddRows.to_offset(Hex::new(0, 1)),
+            Coord::new(0, 1)
+        );
+        assert_eq!(
+            OffsetLayout::EvenRows.to_offset(Hex::new(0, 1)),
+            Coord::new(1, 1)
+        );
+    }
+
+    #[test]
+    fn test_hex_board_round_trip() {
+        let input = "a b c\n d e f\ng h i";
+        let board = HexBoard::<char>::parse_tile_board(input, OffsetLayout::OddRows).unwrap();
+        assert_eq!(board.to_tile_string(), input);
+        let center = OffsetLayout::OddRows.to_hex(Coord::new(1, 1));
+        assert_eq!(board.get_value(center), Some(&'e'));
+        let mut around: Vec<char> = board.neighbors(center).map(|(_, c)| *c).collect();
+        around.sort();
+        assert_eq!(around, vec!['b', 'c', 'd', 'f', 'h', 'i']);
+
+        let corner = board.neighbors(Hex::new(0, 0)).count();
+        assert_eq!(corner, 2);
+
+        let mut board = board;
+        board.set_value(center, 'x');
+        assert_eq!(board.board()[Coord::new(1, 1)], 'x');
+        assert_eq!(board.iter().count(), 9);
+    }
+
+    #[test]
+    fn test_hex_board_errors() {
+        assert!(matches!(
+            HexBoard::<char>::parse_tile_board("a b\n c", OffsetLayout::OddRows),
+            Err(BoardParseError::RaggedRow { line: 2, .. })
+        ));
+        assert!(matches!(
+            HexBoard::<char>::parse_tile_board("a b\nc d", OffsetLayout::OddRows),
+            Err(BoardParseError::BadIndent {
+                line: 2,
+                expected: 1,
+                found: 0
+            })
+        ));
+        assert!(HexBoard::<char>::parse_tile_board("\na b\n c d", OffsetLayout::OddRows).is_ok());
+        assert!(matches!(
+            HexBoard::<char>::parse_tile_board("  a b", OffsetLayout::OddRows),
+            Err(BoardParseError::BadIndent { line: 1, .. })
+        ));
+        assert!(matches!(
+            HexBoard::<char>::parse_tile_board("é ab", OffsetLayout::OddRows),
+            Err(BoardParseError::InvalidCell {
+                line: 1,
+                column: 4,
+                character: 'b'
+            })
+        ));
+        assert!(matches!(
+            "e".parse::<HexDirection>(),
+            Ok(HexDirection::East)
+        ));
+        assert!("n".parse::<HexDirection>().is_err());
+    }
+}
@@ -0,0 +1,175 @@
+use super::{Board, BoardParseError, Coord, Topology};
+
+/// One of the eight rotations and reflections of a rectangle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    FlipHorizontal,
+    FlipVertical,
+    Transpose,
+    AntiTranspose,
+}
+
+impl Transform {
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::Rotate90,
+        Transform::Rotate180,
+        Transform::Rotate270,
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+        Transform::Transpose,
+        Transform::AntiTranspose,
+    ];
+
+    pub fn apply<T: Clone>(&self, board: &Board<T>) -> Board<T> {
+        match self {
+            Transform::Identity => board.clone(),
+            Transform::Rotate90 => board.rotate_90(),
+            Transform::Rotate180 => board.rotate_180(),
+            Transform::Rotate270 => board.rotate_270(),
+            Transform::FlipHorizontal => board.flip_horizontal(),
+            Transform::FlipVertical => board.flip_vertical(),
+            Transform::Transpose => board.transpose(),
+            Transform::AntiTranspose => board.rotate_180().transpose(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PatternMatch {
+    /// Board position of the pattern's top-left cell.
+    pub anchor: Coord,
+    pub transform: Transform,
+}
+
+/// A small grid to search for on a board; `None` cells match anything.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern<T> {
+    cells: Board<Option<T>>,
+}
+
+impl<T> Pattern<T> {
+    pub fn new(cells: Board<Option<T>>) -> Self {
+        Self { cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+}
+
+impl Pattern<char> {
+    pub fn parse(input: &str, wildcard: char) -> Result<Self, BoardParseError> {
+        let cells = Board::parse_board(input, |c: &char| Some((*c != wildcard).then_some(*c)))?;
+        Ok(Self::new(cells))
+    }
+}
+
+impl<T: PartialEq + Clone> Pattern<T> {
+    pub fn transformed(&self, transform: Transform) -> Self {
+        Self::new(transform.apply(&self.cells))
+    }
+
+    pub fn matches_at(&self, board: &Board<T>, anchor: Coord) -> bool {
+        self.cells.iter().all(|(offset, expected)| match expected {
+            None => board.is_in_bound(anchor + offset),
+            Some(expected) => board.get_value(anchor + offset) == Some(expected),
+        })
+    }
+
+    /// Top-left anchors of every placement of the pattern as given.
+    pub fn find_all(&self, board: &Board<T>) -> Vec<Coord> {
+        let (max_x, max_y) = match board.topology() {
+            Topology::Bounded => (
+                (board.width() + 1).checked_sub(self.width()),
+                (board.height() + 1).checked_sub(self.height()),
+            ),
+            Topology::Toroidal => (Some(board.width()), Some(board.height())),
+        };
+        let (Some(max_x), Some(max_y)) = (max_x, max_y) else {
+            return Vec::new();
+        };
+        (0..max_y as i32)
+            .flat_map(|y| (0..max_x as i32).map(move |x| Coord::new(x, y)))
+            .filter(|anchor| self.matches_at(board, *anchor))
+            .collect()
+    }
+
+    /// Placements of any of the eight orientations. Orientations that look the
+    /// same as an earlier one are skipped, so symmetric patterns match once.
+    pub fn find_all_symmetric(&self, board: &Board<T>) -> Vec<PatternMatch> {
+        let mut variants: Vec<(Transform, Pattern<T>)> = Vec::new();
+        for transform in Transform::ALL {
+            let variant = self.transformed(transform);
+            if variants.iter().all(|(_, seen)| *seen != variant) {
+                variants.push((transform, variant));
+            }
+        }
+        variants
+            .iter()
+            .flat_map(|(transform, variant)| {
+                variant
+                    .find_all(board)
+                    .into_iter()
+                    .map(move |anchor| PatternMatch {
+                        anchor,
+                        transform: *transform,
+                    })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_all_distinct() {
+        let board = Board::from_string("ab\ncd\nef").unwrap();
+        let mut seen: Vec<Board<char>> = Vec::new();
+        for transform in Transform::ALL {
+            let result = transform.apply(&board);
+            assert!(!seen.contains(&result), "{:?} repeats", transform);
+            seen.push(result);
+        }
+        assert_eq!(
+            Transform::AntiTranspose.apply(&board).to_string(),
+            "fdb\neca"
+        );
+    }
+
+    #[test]
+    fn test_find_all_with_wildcards() {
+        let board = Board::from_string("#.#.\n.#..\n#.#.").unwrap();
+        let cross = Pattern::parse("#?#\n?#?\n#?#", '?').unwrap();
+        assert_eq!(cross.find_all(&board), [Coord::new(0, 0)]);
+        let pair = Pattern::parse("#?#", '?').unwrap();
+        assert_eq!(pair.find_all(&board), [Coord::new(0, 0), Coord::new(0, 2)]);
+        let too_big = Pattern::parse("#####", '?').unwrap();
+        assert!(too_big.find_all(&board).is_empty());
+    }
+
+    #[test]
+    fn test_find_all_symmetric() {
+        let board = Board::from_string("M.S.\n.A..\nM.S.\n....").unwrap();
+        let x_mas = Pattern::parse("S.S\n.A.\nM.M", '.').unwrap();
+        assert!(x_mas.find_all(&board).is_empty());
+        let matches = x_mas.find_all_symmetric(&board);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].anchor, Coord::new(0, 0));
+        assert_eq!(matches[0].transform, Transform::Rotate90);
+
+        let line = Pattern::parse("ab", '.').unwrap();
+        let board = Board::from_string("ab\nba").unwrap();
+        assert_eq!(line.find_all_symmetric(&board).len(), 4);
+    }
+}
@@ -0,0 +1,159 @@
+use super::{Board, Coord};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Deref;
+
+impl<T: PartialEq> Board<T> {
+    /// Every coordinate holding `value`, in row-major order.
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Coord> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(coord, _)| coord)
+    }
+}
+
+/// Coordinates of a board grouped by the value they hold.
+#[derive(Clone, Debug)]
+pub struct PositionIndex<T> {
+    positions: HashMap<T, HashSet<Coord>>,
+    excluded: HashSet<T>,
+}
+
+impl<T: Eq + Hash + Clone> PositionIndex<T> {
+    pub fn new(board: &Board<T>) -> Self {
+        Self::with_excluded(board, [])
+    }
+
+    /// Builds the index leaving out cells holding any of `excluded`, such as the floor.
+    pub fn with_excluded(board: &Board<T>, excluded: impl IntoIterator<Item = T>) -> Self {
+        let mut index = Self {
+            positions: HashMap::new(),
+            excluded: excluded.into_iter().collect(),
+        };
+        for (coord, value) in board.iter() {
+            index.insert(coord, value);
+        }
+        index
+    }
+
+    fn insert(&mut self, coord: Coord, value: &T) {
+        if !self.excluded.contains(value) {
+            self.positions
+                .entry(value.clone())
+                .or_default()
+                .insert(coord);
+        }
+    }
+
+    fn remove(&mut self, coord: Coord, value: &T) {
+        if let Some(coords) = self.positions.get_mut(value) {
+            coords.remove(&coord);
+            if coords.is_empty() {
+                self.positions.remove(value);
+            }
+        }
+    }
+
+    /// Records that the cell at `coord` changed from `old` to `new`.
+    pub fn update(&mut self, coord: Coord, old: &T, new: &T) {
+        if old != new {
+            self.remove(coord, old);
+            self.insert(coord, new);
+        }
+    }
+
+    pub fn positions(&self, value: &T) -> impl Iterator<Item = Coord> + '_ {
+        self.positions.get(value).into_iter().flatten().copied()
+    }
+
+    pub fn count(&self, value: &T) -> usize {
+        self.positions.get(value).map_or(0, HashSet::len)
+    }
+
+    pub fn groups(&self) -> impl Iterator<Item = (&T, &HashSet<Coord>)> {
+        self.positions.iter()
+    }
+}
+
+/// A board that keeps a [`PositionIndex`] in sync with every `set_value`.
+#[derive(Clone, Debug)]
+pub struct IndexedBoard<T> {
+    board: Board<T>,
+    index: PositionIndex<T>,
+}
+
+impl<T: Eq + Hash + Clone> IndexedBoard<T> {
+    pub fn new(board: Board<T>, excluded: impl IntoIterator<Item = T>) -> Self {
+        let index = PositionIndex::with_excluded(&board, excluded);
+        Self { board, index }
+    }
+
+    pub fn index(&self) -> &PositionIndex<T> {
+        &self.index
+    }
+
+    pub fn set_value(&mut self, coord: Coord, value: T) {
+        let coord = self.board.wrap(coord);
+        let old = std::mem::replace(&mut self.board[coord], value);
+        self.index.update(coord, &old, &self.board[coord]);
+    }
+
+    pub fn positions_of(&self, value: &T) -> impl Iterator<Item = Coord> + '_ {
+        self.index.positions(value)
+    }
+
+    pub fn into_board(self) -> Board<T> {
+        self.board
+    }
+}
+
+impl<T> Deref for IndexedBoard<T> {
+    type Target = Board<T>;
+
+    fn deref(&self) -> &Board<T> {
+        &self.board
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions_of() {
+        let board = Board::from_string("a.a\n.a.").unwrap();
+        let found: Vec<Coord> = board.positions_of(&'a').collect();
+        assert_eq!(
+            found,
+            [Coord::new(0, 0), Coord::new(2, 0), Coord::new(1, 1)]
+        );
+        assert_eq!(board.positions_of(&'z').count(), 0);
+    }
+
+    #[test]
+    fn test_index_with_exclusions() {
+        let board = Board::from_string("0.A\n.0A").unwrap();
+        let index = PositionIndex::with_excluded(&board, ['.']);
+        assert_eq!(index.groups().count(), 2);
+        assert_eq!(index.count(&'.'), 0);
+        let mut antennas: Vec<Coord> = index.positions(&'A').collect();
+        antennas.sort_by_key(|c| c.y);
+        assert_eq!(antennas, [Coord::new(2, 0), Coord::new(2, 1)]);
+    }
+
+    #[test]
+    fn test_indexed_board_updates() {
+        let mut board = IndexedBoard::new(Board::from_string("@.O\n.O.").unwrap(), ['.']);
+        board.set_value(Coord::new(1, 0), '@');
+        board.set_value(Coord::new(0, 0), '.');
+        board.set_value(Coord::new(1, 1), 'O');
+        assert_eq!(
+            board.positions_of(&'@').collect::<Vec<_>>(),
+            [Coord::new(1, 0)]
+        );
+        assert_eq!(board.index().count(&'O'), 2);
+        assert_eq!(board[Coord::new(0, 0)], '.');
+        assert_eq!(board.find_element('@'), Some(Coord::new(1, 0)));
+        assert_eq!(board.into_board().to_string(), ".@O\n.O.");
+    }
+}
@@ -0,0 +1,152 @@
+use super::{Board, Coord};
+
+/// Cells visited by stepping from an origin in a fixed direction. Stops at the
+/// board edge, or on a toroidal board once it comes back to the origin.
+#[derive(Debug)]
+pub struct Ray<'a, T> {
+    board: &'a Board<T>,
+    origin: Coord,
+    direction: Coord,
+    next: Option<Coord>,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Coord, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let coord = self.next.take()?;
+        let value = self.board.get_value(coord)?;
+        let following = self.board.wrap(coord + self.direction);
+        if following != self.origin {
+            self.next = Some(following);
+        }
+        Some((coord, value))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hit<'a, T> {
+    pub coord: Coord,
+    pub value: &'a T,
+    /// Number of steps from the origin to the hit cell.
+    pub distance: usize,
+}
+
+impl<T> Board<T> {
+    /// Walks from `start` (included) in `direction`.
+    pub fn line(&self, start: Coord, direction: Coord) -> Ray<'_, T> {
+        let origin = self.wrap(start);
+        Ray {
+            board: self,
+            origin,
+            direction,
+            next: Some(origin),
+        }
+    }
+
+    /// Walks from the cell after `start` in `direction`.
+    pub fn ray(&self, start: Coord, direction: Coord) -> Ray<'_, T> {
+        let mut ray = self.line(start, direction);
+        let first = self.wrap(start + direction);
+        ray.next = if first == ray.origin {
+            None
+        } else {
+            Some(first)
+        };
+        ray
+    }
+
+    /// Like [`Board::ray`], but stops before the first cell for which `blocked` holds.
+    pub fn ray_until<'a, F>(
+        &'a self,
+        start: Coord,
+        direction: Coord,
+        blocked: F,
+    ) -> impl Iterator<Item = (Coord, &'a T)> + 'a
+    where
+        F: Fn(&T) -> bool + 'a,
+    {
+        self.ray(start, direction)
+            .take_while(move |(_, value)| !blocked(value))
+    }
+
+    pub fn first_hit<F>(&self, start: Coord, direction: Coord, blocked: F) -> Option<Hit<'_, T>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.ray(start, direction)
+            .enumerate()
+            .find(|(_, (_, value))| blocked(value))
+            .map(|(i, (coord, value))| Hit {
+                coord,
+                value,
+                distance: i + 1,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Topology;
+
+    #[test]
+    fn test_line_and_ray() {
+        let board = Board::from_string("abcd\nefgh\nijkl").unwrap();
+        let line: String = board
+            .line(Coord::new(0, 0), Coord::new(1, 1))
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(line, "afk");
+        let ray: Vec<Coord> = board
+            .ray(Coord::new(1, 2), Coord::new(0, -1))
+            .map(|(c, _)| c)
+            .collect();
+        assert_eq!(ray, [Coord::new(1, 1), Coord::new(1, 0)]);
+        assert_eq!(board.line(Coord::new(2, 1), Coord::new(0, 0)).count(), 1);
+        assert_eq!(board.ray(Coord::new(2, 1), Coord::new(0, 0)).count(), 0);
+    }
+
+    #[test]
+    fn test_ray_until_and_first_hit() {
+        let board = Board::from_string("..#.\n....\n^..#").unwrap();
+        let walk: Vec<Coord> = board
+            .ray_until(Coord::new(0, 2), Coord::new(1, 0), |c| *c == '#')
+            .map(|(c, _)| c)
+            .collect();
+        assert_eq!(walk, [Coord::new(1, 2), Coord::new(2, 2)]);
+        assert_eq!(
+            board.first_hit(Coord::new(0, 2), Coord::new(1, 0), |c| *c == '#'),
+            Some(Hit {
+                coord: Coord::new(3, 2),
+                value: &'#',
+                distance: 3
+            })
+        );
+        assert_eq!(
+            board.first_hit(Coord::new(0, 2), Coord::new(0, -1), |c| *c == '#'),
+            None
+        );
+    }
+
+    #[test]
+    fn test_toroidal_ray_stops_at_origin() {
+        let board = Board::from_string("abc\ndef")
+            .unwrap()
+            .with_topology(Topology::Toroidal);
+        let ray: String = board
+            .ray(Coord::new(1, 0), Coord::new(1, 0))
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(ray, "ca");
+        let diagonal: String = board
+            .line(Coord::new(0, 0), Coord::new(1, 1))
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(diagonal, "aecdbf");
+        let hit = board
+            .first_hit(Coord::new(2, 1), Coord::new(1, 0), |c| *c == 'e')
+            .unwrap();
+        assert_eq!((hit.coord, hit.distance), (Coord::new(1, 1), 2));
+    }
+}
@@ -0,0 +1,349 @@
+use super::{Board, Coord, NEIGHBORS_4, NEIGHBORS_8};
+use crate::rect::Rect;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+const DIAGONALS: [Coord; 4] = [
+    Coord { x: -1, y: -1 },
+    Coord { x: 1, y: -1 },
+    Coord { x: 1, y: 1 },
+    Coord { x: -1, y: 1 },
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RegionId(pub usize);
+
+impl Display for RegionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    pub fn deltas(&self) -> &'static [Coord] {
+        match self {
+            Connectivity::Four => &NEIGHBORS_4,
+            Connectivity::Eight => &NEIGHBORS_8,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Regions {
+    labels: Board<RegionId>,
+    members: Vec<Vec<Coord>>,
+}
+
+impl Regions {
+    pub fn labels(&self) -> &Board<RegionId> {
+        &self.labels
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    pub fn members(&self, id: RegionId) -> &[Coord] {
+        &self.members[id.0]
+    }
+
+    pub fn region_of(&self, coord: Coord) -> Option<RegionId> {
+        self.labels.get_value(coord).copied()
+    }
+
+    pub fn region(&self, id: RegionId) -> Region {
+        Region::new(self.members(id).iter().copied())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (RegionId, &[Coord])> + '_ {
+        self.members
+            .iter()
+            .enumerate()
+            .map(|(id, cells)| (RegionId(id), cells.as_slice()))
+    }
+
+    pub fn into_parts(self) -> (Board<RegionId>, Vec<Vec<Coord>>) {
+        (self.labels, self.members)
+    }
+}
+
+/// An arbitrary set of cells with the geometry of the shape they cover.
+/// The geometry is always that of a bounded plane: a region labelled on a
+/// toroidal board keeps the perimeter, corners and sides it would have
+/// without wrapping, since cells across a wrapped edge are not neighbors here.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    cells: HashSet<Coord>,
+}
+
+impl Region {
+    pub fn new(cells: impl IntoIterator<Item = Coord>) -> Self {
+        Self {
+            cells: cells.into_iter().collect(),
+        }
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.cells.contains(&coord)
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells.iter().copied()
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Number of cell edges that face a cell outside the region.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .flat_map(|coord| NEIGHBORS_4.iter().map(move |delta| *coord + *delta))
+            .filter(|neighbor| !self.contains(*neighbor))
+            .count()
+    }
+
+    /// Corners where the boundary turns around the region (both sides outside).
+    pub fn convex_corners(&self) -> usize {
+        self.count_corners(|side_a, side_b, _| !side_a && !side_b)
+    }
+
+    /// Corners where the boundary turns into the region (both sides inside, diagonal outside).
+    pub fn concave_corners(&self) -> usize {
+        self.count_corners(|side_a, side_b, diagonal| side_a && side_b && !diagonal)
+    }
+
+    pub fn corners(&self) -> usize {
+        self.convex_corners() + self.concave_corners()
+    }
+
+    /// Straight boundary segments, counting the edges of holes as well.
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    fn count_corners(&self, is_corner: impl Fn(bool, bool, bool) -> bool) -> usize {
+        self.cells
+            .iter()
+            .flat_map(|coord| DIAGONALS.iter().map(move |delta| (*coord, *delta)))
+            .filter(|(coord, delta)| {
+                is_corner(
+                    self.contains(Coord::new(coord.x + delta.x, coord.y)),
+                    self.contains(Coord::new(coord.x, coord.y + delta.y)),
+                    self.contains(*coord + *delta),
+                )
+            })
+            .count()
+    }
+
+    /// Smallest and largest corner of the region, both inclusive.
+    pub fn bounding_box(&self) -> Option<Rect> {
+        Rect::bounding_box(self.cells.iter().copied())
+    }
+
+    /// Enclosed pockets of outside cells; they may leak through diagonal gaps.
+    pub fn holes(&self) -> Vec<Region> {
+        let Some(rect) = self.bounding_box() else {
+            return Vec::new();
+        };
+        let origin = rect.top_left - Coord::new(1, 1);
+        let width = rect.width() as usize + 2;
+        let height = rect.height() as usize + 2;
+        let mask: Vec<bool> = (0..height as i32)
+            .flat_map(|y| (0..width as i32).map(move |x| Coord::new(x, y)))
+            .map(|coord| self.contains(origin + coord))
+            .collect();
+        let mask = Board::from_vec(width, height, mask);
+
+        let outside = mask.label_regions_by(Connectivity::Eight, |a, b| !a && !b);
+        let exterior = outside.region_of(Coord::new(0, 0));
+        outside
+            .iter()
+            .filter(|(id, cells)| Some(*id) != exterior && !mask[cells[0]])
+            .map(|(_, cells)| Region::new(cells.iter().map(|coord| origin + *coord)))
+            .collect()
+    }
+}
+
+impl<T> Board<T> {
+    /// Every cell reachable from `start` through neighbors for which
+    /// `connected(current, neighbor)` holds, in discovery order.
+    pub fn flood_fill<F>(
+        &self,
+        start: Coord,
+        connectivity: Connectivity,
+        connected: F,
+    ) -> Vec<Coord>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let mut seen = vec![false; self.cells.len()];
+        match self.index_of(start) {
+            Some(i) => seen[i] = true,
+            None => return Vec::new(),
+        }
+        self.fill_from(self.wrap(start), connectivity, &connected, &mut seen)
+    }
+
+    fn fill_from<F>(
+        &self,
+        start: Coord,
+        connectivity: Connectivity,
+        connected: &F,
+        seen: &mut [bool],
+    ) -> Vec<Coord>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let mut cells = vec![start];
+        let mut stack = vec![start];
+        while let Some(coord) = stack.pop() {
+            let value = &self[coord];
+            for (neighbor, neighbor_value) in self.neighbors(coord, connectivity.deltas()) {
+                let i = self.index_of(neighbor).unwrap();
+                if !seen[i] && connected(value, neighbor_value) {
+                    seen[i] = true;
+                    cells.push(neighbor);
+                    stack.push(neighbor);
+                }
+            }
+        }
+        cells
+    }
+
+    /// Splits the board into regions of cells joined by `connected`; ids follow
+    /// the row-major order of each region's first cell.
+    pub fn label_regions_by<F>(&self, connectivity: Connectivity, connected: F) -> Regions
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let mut seen = vec![false; self.cells.len()];
+        let mut labels = vec![RegionId(0); self.cells.len()];
+        let mut members = Vec::new();
+        for i in 0..self.cells.len() {
+            if seen[i] {
+                continue;
+            }
+            seen[i] = true;
+            let cells = self.fill_from(self.coord_of(i), connectivity, &connected, &mut seen);
+            for coord in &cells {
+                labels[self.index_of(*coord).unwrap()] = RegionId(members.len());
+            }
+            members.push(cells);
+        }
+        Regions {
+            labels: Board::from_vec(self.width, self.height, labels).with_topology(self.topology),
+            members,
+        }
+    }
+}
+
+impl<T: PartialEq> Board<T> {
+    pub fn label_regions(&self, connectivity: Connectivity) -> Regions {
+        self.label_regions_by(connectivity, |a, b| a == b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Topology;
+
+    #[test]
+    fn test_label_regions() {
+        let board = Board::from_string("AAB\nABB\nCCA").unwrap();
+        let regions = board.label_regions(Connectivity::Four);
+        assert_eq!(regions.len(), 4);
+        assert_eq!(regions.labels().to_string(), "001\n011\n223");
+        assert_eq!(regions.members(RegionId(1)).len(), 3);
+        assert_eq!(regions.region_of(Coord::new(2, 2)), Some(RegionId(3)));
+
+        let checkers = Board::from_string("AB\nBA").unwrap();
+        assert_eq!(checkers.label_regions(Connectivity::Four).len(), 4);
+        let diagonal = checkers.label_regions(Connectivity::Eight);
+        assert_eq!(diagonal.len(), 2);
+        assert_eq!(diagonal.region_of(Coord::new(1, 1)), Some(RegionId(0)));
+    }
+
+    #[test]
+    fn test_region_metrics() {
+        let board = Board::from_string("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA").unwrap();
+        let regions = board.label_regions(Connectivity::Four);
+        let outer = regions.region(regions.region_of(Coord::new(0, 0)).unwrap());
+        assert_eq!(outer.area(), 28);
+        assert_eq!(outer.perimeter(), 40);
+        assert_eq!(outer.sides(), 12);
+        assert_eq!(outer.convex_corners(), 6);
+        assert_eq!(outer.concave_corners(), 6);
+        assert_eq!(
+            outer.bounding_box(),
+            Some(Rect::new(Coord::new(0, 0), Coord::new(5, 5)))
+        );
+        assert_eq!(outer.holes().len(), 1);
+        assert_eq!(outer.holes()[0].area(), 8);
+
+        let block = regions.region(regions.region_of(Coord::new(3, 1)).unwrap());
+        assert_eq!((block.area(), block.perimeter(), block.sides()), (4, 8, 4));
+        assert!(block.holes().is_empty());
+
+        let wrapped = Board::from_string("AA")
+            .unwrap()
+            .with_topology(Topology::Toroidal);
+        let regions = wrapped.label_regions(Connectivity::Four);
+        let strip = regions.region(RegionId(0));
+        assert_eq!((strip.perimeter(), strip.sides()), (6, 4));
+    }
+
+    #[test]
+    fn test_ring_with_two_holes() {
+        let ring = Board::from_string("#####\n#.#.#\n#####").unwrap();
+        let region = ring.flood_fill(Coord::new(0, 0), Connectivity::Four, |a, b| a == b);
+        let region = Region::new(region);
+        assert_eq!(region.area(), 13);
+        assert_eq!(region.perimeter(), 16 + 8);
+        assert_eq!(region.sides(), 12);
+        assert_eq!(region.holes().len(), 2);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let board = Board::parse_int_board("1290\n1390\n9999").unwrap();
+        let mut basin = board.flood_fill(Coord::new(0, 0), Connectivity::Four, |_, b| *b != 9);
+        basin.sort_by_key(|c| (c.y, c.x));
+        assert_eq!(
+            basin,
+            [
+                Coord::new(0, 0),
+                Coord::new(1, 0),
+                Coord::new(0, 1),
+                Coord::new(1, 1)
+            ]
+        );
+        assert!(board
+            .flood_fill(Coord::new(9, 9), Connectivity::Four, |_, _| true)
+            .is_empty());
+
+        let wrapped = board.clone().with_topology(Topology::Toroidal);
+        let basin = wrapped.flood_fill(Coord::new(0, 0), Connectivity::Four, |_, b| *b != 9);
+        assert_eq!(basin.len(), 6);
+    }
+
+    #[test]
+    fn test_large_region_does_not_overflow() {
+        let board = Board::from_vec(500, 500, vec!['.'; 250_000]);
+        let regions = board.label_regions(Connectivity::Four);
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions.members(RegionId(0)).len(), 250_000);
+    }
+}
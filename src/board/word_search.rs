@@ -0,0 +1,144 @@
+use super::{Board, Coord, NEIGHBORS_8};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WordMatch {
+    pub word: String,
+    pub start: Coord,
+    pub direction: Coord,
+}
+
+#[derive(Clone, Debug, Default)]
+struct TrieNode {
+    children: HashMap<char, usize>,
+    word: Option<usize>,
+}
+
+/// Finds a set of words in all eight directions, sharing one trie walk per
+/// start cell and direction.
+#[derive(Clone, Debug)]
+pub struct WordSearch {
+    nodes: Vec<TrieNode>,
+    words: Vec<String>,
+}
+
+impl WordSearch {
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut search = Self {
+            nodes: vec![TrieNode::default()],
+            words: Vec::new(),
+        };
+        for word in words {
+            search.insert(word.as_ref());
+        }
+        search
+    }
+
+    fn insert(&mut self, word: &str) {
+        if word.is_empty() {
+            return;
+        }
+        let mut node = 0;
+        for c in word.chars() {
+            node = match self.nodes[node].children.get(&c) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(TrieNode::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(c, child);
+                    child
+                }
+            };
+        }
+        if self.nodes[node].word.is_none() {
+            self.nodes[node].word = Some(self.words.len());
+            self.words.push(word.to_string());
+        }
+    }
+
+    /// Every occurrence of every word. A one-character word reads the same in
+    /// all directions, so it is reported once per cell, with the first direction.
+    pub fn find_all(&self, board: &Board<char>) -> Vec<WordMatch> {
+        let mut matches = Vec::new();
+        for (start, _) in board.iter() {
+            for (d, direction) in NEIGHBORS_8.into_iter().enumerate() {
+                let mut node = 0;
+                for (step, (_, c)) in board.line(start, direction).enumerate() {
+                    match self.nodes[node].children.get(c) {
+                        Some(&child) => node = child,
+                        None => break,
+                    }
+                    if step == 0 && d > 0 {
+                        continue;
+                    }
+                    if let Some(word) = self.nodes[node].word {
+                        matches.push(WordMatch {
+                            word: self.words[word].clone(),
+                            start,
+                            direction,
+                        });
+                    }
+                }
+            }
+        }
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_words_in_all_directions() {
+        let board = Board::from_string("CATS\nXOXX\nXXGX").unwrap();
+        let search = WordSearch::new(["CAT", "CATS", "DOG", "GOC", "TAC"]);
+        let mut found: Vec<(String, Coord, Coord)> = search
+            .find_all(&board)
+            .into_iter()
+            .map(|m| (m.word, m.start, m.direction))
+            .collect();
+        found.sort_by_key(|(word, _, _)| word.clone());
+        assert_eq!(
+            found,
+            [
+                ("CAT".to_string(), Coord::new(0, 0), Coord::new(1, 0)),
+                ("CATS".to_string(), Coord::new(0, 0), Coord::new(1, 0)),
+                ("GOC".to_string(), Coord::new(2, 2), Coord::new(-1, -1)),
+                ("TAC".to_string(), Coord::new(2, 0), Coord::new(-1, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_single_character_word() {
+        let board = Board::from_string("AB\nCA").unwrap();
+        let found = WordSearch::new(["A", "AB"]).find_all(&board);
+        let starts: Vec<(&str, Coord)> = found.iter().map(|m| (m.word.as_str(), m.start)).collect();
+        assert_eq!(
+            starts,
+            [
+                ("A", Coord::new(0, 0)),
+                ("AB", Coord::new(0, 0)),
+                ("A", Coord::new(1, 1)),
+                ("AB", Coord::new(1, 1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_non_square_board() {
+        let board = Board::from_string("XMAS\nXXXX").unwrap();
+        let tall = board.transpose();
+        let search = WordSearch::new(["XMAS"]);
+        assert_eq!(search.find_all(&board).len(), 1);
+        let found = search.find_all(&tall);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].start, Coord::new(0, 0));
+        assert_eq!(found[0].direction, Coord::new(0, 1));
+    }
+}
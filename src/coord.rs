@@ -0,0 +1,486 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
+
+/// Signed integer types a coordinate can be built from.
+pub trait Integer:
+    Copy
+    + Debug
+    + Ord
+    + Hash
+    + From<i32>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$t>::rem_euclid(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i32, i64, i128);
+
+fn gcd<N: Integer>(a: N, b: N) -> N {
+    if b == N::ZERO {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Coord<N = i32> {
+    pub x: N,
+    pub y: N,
+}
+
+impl<N: Integer> Coord<N> {
+    pub fn new(x: N, y: N) -> Coord<N> {
+        Coord { x, y }
+    }
+
+    pub fn up(&self) -> Coord<N> {
+        Coord::new(self.x, self.y - N::ONE)
+    }
+
+    pub fn down(&self) -> Coord<N> {
+        Coord::new(self.x, self.y + N::ONE)
+    }
+
+    pub fn left(&self) -> Coord<N> {
+        Coord::new(self.x - N::ONE, self.y)
+    }
+
+    pub fn right(&self) -> Coord<N> {
+        Coord::new(self.x + N::ONE, self.y)
+    }
+
+    /// The neighbor one step away in `direction`.
+    pub fn step(&self, direction: impl Into<Coord<N>>) -> Coord<N> {
+        *self + direction.into()
+    }
+
+    pub fn neighbors4(&self) -> impl Iterator<Item = Coord<N>> {
+        let coord = *self;
+        [coord.up(), coord.right(), coord.down(), coord.left()].into_iter()
+    }
+
+    /// The eight surrounding cells, clockwise from up.
+    pub fn neighbors8(&self) -> impl Iterator<Item = Coord<N>> {
+        let coord = *self;
+        [
+            coord.up(),
+            coord.up().right(),
+            coord.right(),
+            coord.down().right(),
+            coord.down(),
+            coord.down().left(),
+            coord.left(),
+            coord.up().left(),
+        ]
+        .into_iter()
+    }
+
+    /// Wraps each component into `0..size`, as on a torus of that size.
+    pub fn rem_euclid(&self, size: Coord<N>) -> Coord<N> {
+        Coord::new(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+    }
+
+    pub fn manhattan(&self, other: Coord<N>) -> N {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(&self, other: Coord<N>) -> N {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The smallest step in the same direction, e.g. `(4, -6)` becomes `(2, -3)`.
+    pub fn reduced(&self) -> Coord<N> {
+        let divisor = gcd(self.x.abs(), self.y.abs());
+        if divisor == N::ZERO {
+            *self
+        } else {
+            *self / divisor
+        }
+    }
+
+    /// Converts to another integer width, failing if a component does not fit.
+    pub fn try_cast<M>(&self) -> Result<Coord<M>, M::Error>
+    where
+        M: Integer + TryFrom<N>,
+    {
+        Ok(Coord::new(M::try_from(self.x)?, M::try_from(self.y)?))
+    }
+}
+
+/// Reading order: by row, then by column.
+impl<N: Ord> Ord for Coord<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.y.cmp(&other.y).then_with(|| self.x.cmp(&other.x))
+    }
+}
+
+impl<N: Ord> PartialOrd for Coord<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: Integer> Add for Coord<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<N: Integer> Mul for Coord<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.x * rhs.x, self.y * rhs.y)
+    }
+}
+
+impl<N: Integer> Sub for Coord<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<N: Integer> Mul<N> for Coord<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: N) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<N: Integer> Div<N> for Coord<N> {
+    type Output = Self;
+
+    fn div(self, rhs: N) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl<N: Integer> Neg for Coord<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<N: Integer> AddAssign for Coord<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<N: Integer> SubAssign for Coord<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Point3<N = i32> {
+    pub x: N,
+    pub y: N,
+    pub z: N,
+}
+
+impl<N: Integer> Point3<N> {
+    pub fn new(x: N, y: N, z: N) -> Point3<N> {
+        Point3 { x, y, z }
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbors6(&self) -> impl Iterator<Item = Point3<N>> {
+        let (zero, one) = (N::ZERO, N::ONE);
+        let point = *self;
+        [
+            Point3::new(one, zero, zero),
+            Point3::new(-one, zero, zero),
+            Point3::new(zero, one, zero),
+            Point3::new(zero, -one, zero),
+            Point3::new(zero, zero, one),
+            Point3::new(zero, zero, -one),
+        ]
+        .into_iter()
+        .map(move |delta| point + delta)
+    }
+
+    /// The 26 points of the surrounding cube.
+    pub fn neighbors26(&self) -> impl Iterator<Item = Point3<N>> {
+        let point = *self;
+        let steps = [-N::ONE, N::ZERO, N::ONE];
+        steps
+            .into_iter()
+            .flat_map(move |z| {
+                steps
+                    .into_iter()
+                    .flat_map(move |y| steps.into_iter().map(move |x| Point3::new(x, y, z)))
+            })
+            .filter(|delta| *delta != Point3::new(N::ZERO, N::ZERO, N::ZERO))
+            .map(move |delta| point + delta)
+    }
+
+    /// Wraps each component into `0..size`.
+    pub fn rem_euclid(&self, size: Point3<N>) -> Point3<N> {
+        Point3::new(
+            self.x.rem_euclid(size.x),
+            self.y.rem_euclid(size.y),
+            self.z.rem_euclid(size.z),
+        )
+    }
+
+    pub fn manhattan(&self, other: Point3<N>) -> N {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(&self, other: Point3<N>) -> N {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    /// The smallest step in the same direction.
+    pub fn reduced(&self) -> Point3<N> {
+        let divisor = gcd(gcd(self.x.abs(), self.y.abs()), self.z.abs());
+        if divisor == N::ZERO {
+            *self
+        } else {
+            *self / divisor
+        }
+    }
+
+    /// Converts to another integer width, failing if a component does not fit.
+    pub fn try_cast<M>(&self) -> Result<Point3<M>, M::Error>
+    where
+        M: Integer + TryFrom<N>,
+    {
+        Ok(Point3::new(
+            M::try_from(self.x)?,
+            M::try_from(self.y)?,
+            M::try_from(self.z)?,
+        ))
+    }
+}
+
+/// Reading order extended to layers: by `z`, then row, then column.
+impl<N: Ord> Ord for Point3<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.z
+            .cmp(&other.z)
+            .then_with(|| self.y.cmp(&other.y))
+            .then_with(|| self.x.cmp(&other.x))
+    }
+}
+
+impl<N: Ord> PartialOrd for Point3<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: Integer> Add for Point3<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<N: Integer> Mul for Point3<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+}
+
+impl<N: Integer> Sub for Point3<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<N: Integer> Mul<N> for Point3<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: N) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<N: Integer> Div<N> for Point3<N> {
+    type Output = Self;
+
+    fn div(self, rhs: N) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl<N: Integer> Neg for Point3<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<N: Integer> AddAssign for Point3<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<N: Integer> SubAssign for Point3<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Lossless conversions to a wider integer type.
+macro_rules! impl_widen {
+    ($($from:ty => $to:ty),*) => {
+        $(
+            impl From<Coord<$from>> for Coord<$to> {
+                fn from(coord: Coord<$from>) -> Self {
+                    Coord::new(coord.x.into(), coord.y.into())
+                }
+            }
+
+            impl From<Point3<$from>> for Point3<$to> {
+                fn from(point: Point3<$from>) -> Self {
+                    Point3::new(point.x.into(), point.y.into(), point.z.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_widen!(i32 => i64, i32 => i128, i64 => i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coord_arithmetic() {
+        let mut coord = Coord::new(3, -4);
+        assert_eq!(-coord, Coord::new(-3, 4));
+        assert_eq!(coord * 2, Coord::new(6, -8));
+        assert_eq!(coord / 2, Coord::new(1, -2));
+        coord += Coord::new(1, 1);
+        assert_eq!(coord, Coord::new(4, -3));
+        coord -= Coord::new(4, 0);
+        assert_eq!(coord, Coord::new(0, -3));
+        assert_eq!(
+            Coord::new(-1, 105).rem_euclid(Coord::new(101, 103)),
+            Coord::new(100, 2)
+        );
+    }
+
+    #[test]
+    fn test_coord_metrics() {
+        let (a, b) = (Coord::new(1, 1), Coord::new(4, -3));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Coord::new(4, -6).reduced(), Coord::new(2, -3));
+        assert_eq!(Coord::new(0, -5).reduced(), Coord::new(0, -1));
+        assert_eq!(Coord::new(0, 0).reduced(), Coord::new(0, 0));
+
+        let mut coords = vec![Coord::new(0, 1), Coord::new(2, 0), Coord::new(1, 1)];
+        coords.sort();
+        assert_eq!(
+            coords,
+            vec![Coord::new(2, 0), Coord::new(0, 1), Coord::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_wide_coords() {
+        let offset: Coord<i64> = Coord::new(10_000_000_000_000, 10_000_000_000_000);
+        let prize = Coord::<i64>::from(Coord::new(8400, 5400)) + offset;
+        assert_eq!(prize.manhattan(Coord::new(0, 0)), 20_000_000_013_800);
+        assert_eq!(Coord::<i128>::new(1 << 100, 0).right().x, (1 << 100) + 1);
+
+        assert_eq!(
+            Coord::<i64>::new(3, -4).try_cast::<i32>(),
+            Ok(Coord::new(3, -4))
+        );
+        assert!(prize.try_cast::<i32>().is_err());
+        assert_eq!(prize.try_cast::<i128>().unwrap().x, 10_000_000_008_400);
+    }
+
+    #[test]
+    fn test_coord_neighbors() {
+        let center = Coord::new(5i64, 5);
+        assert_eq!(center.neighbors4().count(), 4);
+        assert!(center.neighbors8().all(|n| n.chebyshev(center) == 1));
+        assert_eq!(
+            center
+                .neighbors8()
+                .filter(|n| n.manhattan(center) == 2)
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_point3() {
+        let (a, b) = (Point3::new(1, 2, 3), Point3::new(-1, 5, 3));
+        assert_eq!(a + b, Point3::new(0, 7, 6));
+        assert_eq!(a - b, Point3::new(2, -3, 0));
+        assert_eq!(-a * 2, Point3::new(-2, -4, -6));
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+        assert_eq!(Point3::new(4, -6, 8).reduced(), Point3::new(2, -3, 4));
+        assert_eq!(
+            Point3::new(-1, 3, 5).rem_euclid(Point3::new(3, 3, 3)),
+            Point3::new(2, 0, 2)
+        );
+        assert!(Point3::new(0, 9, 0) > Point3::new(9, 0, 0));
+        assert!(Point3::new(0, 0, 1) > Point3::new(9, 9, 0));
+
+        assert_eq!(a.neighbors6().count(), 6);
+        assert!(a.neighbors6().all(|n| n.manhattan(a) == 1));
+        assert_eq!(a.neighbors26().count(), 26);
+        assert!(a.neighbors26().all(|n| n.chebyshev(a) == 1));
+
+        let wide: Point3<i128> = a.into();
+        assert_eq!(wide.try_cast::<i32>(), Ok(a));
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// A trajectory that enters a loop: `state` is first seen after `tail` steps
+/// and seen again every `length` steps after that.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<S> {
+    pub tail: usize,
+    pub length: usize,
+    pub state: S,
+}
+
+impl<S> Cycle<S> {
+    /// Number of steps needed to reach step `n` while staying inside the first lap.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.tail {
+            n
+        } else {
+            self.tail + (n - self.tail) % self.length
+        }
+    }
+}
+
+/// Steps from `start` until a state repeats, remembering every state seen.
+/// Returns `None` if `step` ends the trajectory first.
+pub fn find_cycle<S, F>(start: S, mut step: F) -> Option<Cycle<S>>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut seen = HashMap::new();
+    let mut state = start;
+    for i in 0.. {
+        if let Some(&tail) = seen.get(&state) {
+            return Some(Cycle {
+                tail,
+                length: i - tail,
+                state,
+            });
+        }
+        let next = step(&state)?;
+        seen.insert(state, i);
+        state = next;
+    }
+    unreachable!()
+}
+
+/// Brent's algorithm: finds the cycle while holding only two states at a time,
+/// at the cost of stepping the trajectory roughly three times.
+pub fn find_cycle_brent<S, F>(start: S, mut step: F) -> Option<Cycle<S>>
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start)?;
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        hare = step(&hare)?;
+    }
+    let mut tail = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        tail += 1;
+    }
+    Some(Cycle {
+        tail,
+        length,
+        state: tortoise,
+    })
+}
+
+/// Like [`find_cycle`], but remembers only a 64-bit hash of each state, which
+/// suits large boards. A hash match is confirmed by replaying the trajectory
+/// to every earlier step with that hash, so collisions cost time but never
+/// give a wrong answer.
+pub fn find_cycle_fingerprint<S, F>(start: S, mut step: F) -> Option<Cycle<S>>
+where
+    S: Clone + PartialEq + Hash,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut state = start.clone();
+    for i in 0.. {
+        let fingerprint = fingerprint(&state);
+        for &tail in seen.get(&fingerprint).into_iter().flatten() {
+            let earlier = nth(&start, tail, &mut step)?;
+            if earlier == state {
+                return Some(Cycle {
+                    tail,
+                    length: i - tail,
+                    state,
+                });
+            }
+        }
+        seen.entry(fingerprint).or_default().push(i);
+        state = step(&state)?;
+    }
+    unreachable!()
+}
+
+fn fingerprint<S: Hash>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn nth<S: Clone, F: FnMut(&S) -> Option<S>>(start: &S, n: usize, step: &mut F) -> Option<S> {
+    let mut state = start.clone();
+    for _ in 0..n {
+        state = step(&state)?;
+    }
+    Some(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::{Board, Coord};
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 3 ...
+    fn rho(n: &u32) -> Option<u32> {
+        Some(if *n == 5 { 3 } else { n + 1 })
+    }
+
+    #[test]
+    fn test_strategies_agree() {
+        let expected = Some(Cycle {
+            tail: 3,
+            length: 3,
+            state: 3,
+        });
+        assert_eq!(find_cycle(0, rho), expected);
+        assert_eq!(find_cycle_brent(0, rho), expected);
+        assert_eq!(find_cycle_fingerprint(0, rho), expected);
+
+        let pure = Some(Cycle {
+            tail: 0,
+            length: 7,
+            state: 0,
+        });
+        assert_eq!(find_cycle_brent(0, |n| Some((n + 1) % 7)), pure);
+        assert_eq!(find_cycle(0, |_| Some(0)).map(|c| c.length), Some(1));
+    }
+
+    /// Hashes only the parity, so most states collide.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Parity(u32);
+
+    impl Hash for Parity {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            (self.0 % 2).hash(state);
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_collisions() {
+        let cycle = find_cycle_fingerprint(Parity(0), |p| rho(&p.0).map(Parity));
+        assert_eq!(
+            cycle,
+            Some(Cycle {
+                tail: 3,
+                length: 3,
+                state: Parity(3),
+            })
+        );
+    }
+
+    #[test]
+    fn test_terminating_trajectory() {
+        let step = |n: &u32| (*n < 10).then(|| n + 1);
+        assert_eq!(find_cycle(0, step), None);
+        assert_eq!(find_cycle_brent(0, step), None);
+        assert_eq!(find_cycle_fingerprint(0, step), None);
+    }
+
+    #[test]
+    fn test_board_states() {
+        let board = Board::from_string("#..\n...").unwrap();
+        let shift = |board: &Board<char>| {
+            let mut next = board.clone();
+            for (coord, cell) in next.iter_mut() {
+                *cell = board[Coord::new((coord.x + 2) % 3, coord.y)];
+            }
+            Some(next)
+        };
+        let cycle = find_cycle_fingerprint(board.clone(), shift).unwrap();
+        assert_eq!((cycle.tail, cycle.length), (0, 3));
+        assert_eq!(cycle.state, board);
+        assert_eq!(cycle.reduce(1_000_000_000), 1);
+    }
+}
@@ -0,0 +1,56 @@
+/// Splits a document into the blocks separated by one or more blank lines.
+pub fn sections(document: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for line in document.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(s) = start.take() {
+                sections.push(&document[s..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end_matches(['\r', '\n']).len();
+        }
+        offset += line.len();
+    }
+    if let Some(s) = start {
+        sections.push(&document[s..end]);
+    }
+    sections
+}
+
+/// Body of the section whose first line is `name` (optionally followed by `:`).
+pub fn section<'a>(document: &'a str, name: &str) -> Option<&'a str> {
+    sections(document).into_iter().find_map(|section| {
+        let (header, body) = section.split_once('\n').unwrap_or((section, ""));
+        let header = header.trim();
+        if header == name || header.strip_suffix(':') == Some(name) {
+            Some(body)
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let document = "\n#.#\n.#.\n\n\n<>^v\r\n\r\nlast\n";
+        assert_eq!(sections(document), ["#.#\n.#.", "<>^v", "last"]);
+        assert!(sections("\n \n").is_empty());
+    }
+
+    #[test]
+    fn test_named_section() {
+        let document = "Map:\n..#\n#..\n\nMoves:\n<<>>\n\nEmpty";
+        assert_eq!(section(document, "Map"), Some("..#\n#.."));
+        assert_eq!(section(document, "Moves"), Some("<<>>"));
+        assert_eq!(section(document, "Empty"), Some(""));
+        assert_eq!(section(document, "Other"), None);
+    }
+}
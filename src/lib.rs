@@ -1,2 +1,8 @@
+pub mod automaton;
 pub mod board;
-pub mod path_finding;
\ No newline at end of file
+pub mod coord;
+pub mod cycle;
+pub mod input;
+pub mod path_finding;
+pub mod rect;
+pub mod sparse_board;
@@ -1,9 +1,10 @@
-use crate::board::{Board, Coord};
+use crate::board::{Coord, Grid};
 use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::hash::Hash;
 
 pub trait StateTrait: Eq + PartialEq + Clone + Hash {
     fn get_coord(&self) -> Coord;
+    fn with_coord(&self, coord: Coord) -> Self;
 }
 
 pub trait NodeTrait {
@@ -16,17 +17,20 @@ pub trait NodeTrait {
     fn new(state: Self::State, cost: usize, h_cost: usize) -> Self;
 }
 
-pub fn a_star<S, N, F>(
-    board: &Board<char>,
+pub fn a_star<G, S, N, F, P>(
+    grid: &G,
     start: S,
     end: S,
     get_neighbors: F,
+    is_open: P,
     one_path: bool,
 ) -> (usize, HashMap<N, HashSet<N>>)
 where
+    G: Grid,
     S: StateTrait,
     N: NodeTrait<State = S> + Ord + Eq + PartialEq + Clone + Hash,
     F: Fn(&N, &S) -> Vec<N>,
+    P: Fn(&G::Cell) -> bool,
 {
     let mut min_score = usize::MAX;
     let mut paths: HashMap<N, HashSet<N>> = HashMap::new();
@@ -39,7 +43,7 @@ where
     while let Some(current_node) = open_queue.pop() {
         let current_coord = current_node.get_state().get_coord();
 
-        if current_coord == end.get_coord() {
+        if current_coord == grid.wrap(end.get_coord()) {
             if min_score > current_node.get_cost() {
                 min_score = current_node.get_cost();
             }
@@ -50,9 +54,17 @@ where
         }
 
         let neighbors = get_neighbors(&current_node, &end);
-        for neighbor_node in neighbors {
-            let value = board.get_value(neighbor_node.get_state().get_coord());
-            if !matches!(value, None | Some('#')) {
+        for mut neighbor_node in neighbors {
+            let coord = neighbor_node.get_state().get_coord();
+            if grid.wrap(coord) != coord {
+                neighbor_node = N::new(
+                    neighbor_node.get_state().with_coord(grid.wrap(coord)),
+                    neighbor_node.get_cost(),
+                    neighbor_node.get_h_cost(),
+                );
+            }
+            let value = grid.get_value(neighbor_node.get_state().get_coord());
+            if value.is_some_and(&is_open) {
                 let score = *g_scores
                     .get(&neighbor_node.get_state())
                     .unwrap_or(&usize::MAX);
@@ -74,3 +86,133 @@ where
 
     (min_score, paths)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::{Board, Topology};
+    use crate::rect::Rect;
+    use crate::sparse_board::SparseBoard;
+    use std::cmp::Ordering;
+
+    #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+    struct State(Coord);
+
+    impl StateTrait for State {
+        fn get_coord(&self) -> Coord {
+            self.0
+        }
+
+        fn with_coord(&self, coord: Coord) -> Self {
+            State(coord)
+        }
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+    struct Node(State, usize);
+
+    impl NodeTrait for Node {
+        type State = State;
+
+        fn get_state(&self) -> State {
+            self.0.clone()
+        }
+
+        fn get_cost(&self) -> usize {
+            self.1
+        }
+
+        fn get_h_cost(&self) -> usize {
+            0
+        }
+
+        fn new(state: State, cost: usize, _h_cost: usize) -> Self {
+            Node(state, cost)
+        }
+    }
+
+    impl Ord for Node {
+        fn cmp(&self, other: &Self) -> Ordering {
+            other.1.cmp(&self.1)
+        }
+    }
+
+    impl PartialOrd for Node {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    fn steps(node: &Node, _end: &State) -> Vec<Node> {
+        let coord = node.0 .0;
+        [coord.up(), coord.down(), coord.left(), coord.right()]
+            .into_iter()
+            .map(|next| Node(State(next), node.1 + 1))
+            .collect()
+    }
+
+    #[test]
+    fn test_a_star_on_int_board() {
+        let board = Board::new(vec![vec![0, 0, 0], vec![1, 1, 0], vec![0, 0, 0]]);
+        let (cost, _) = a_star(
+            &board,
+            State(Coord::new(0, 0)),
+            State(Coord::new(0, 2)),
+            steps,
+            |c| *c == 0,
+            true,
+        );
+        assert_eq!(cost, 6);
+    }
+
+    #[test]
+    fn test_a_star_wraps_on_toroidal_board() {
+        let board = Board::from_string(".#.\n.#.\n.#.")
+            .unwrap()
+            .with_topology(Topology::Toroidal);
+        let (cost, _) = a_star(
+            &board,
+            State(Coord::new(0, 1)),
+            State(Coord::new(2, 1)),
+            steps,
+            |c| *c != '#',
+            true,
+        );
+        assert_eq!(cost, 1);
+    }
+
+    #[test]
+    fn test_a_star_on_sparse_board() {
+        let mut walls = SparseBoard::new('.');
+        for y in -3..=3 {
+            walls.set_value(Coord::new(1, y), '#');
+        }
+        let (cost, _) = a_star(
+            &walls,
+            State(Coord::new(0, 0)),
+            State(Coord::new(2, 0)),
+            steps,
+            |c| *c != '#',
+            true,
+        );
+        assert_eq!(cost, 10);
+    }
+
+    #[test]
+    fn test_a_star_unreachable_on_bounded_sparse_board() {
+        let mut walls =
+            SparseBoard::new('.').with_bounds(Rect::new(Coord::new(-5, -5), Coord::new(5, 5)));
+        for coord in Coord::new(2, 0).neighbors8() {
+            walls.set_value(coord, '#');
+        }
+        let (cost, _) = a_star(
+            &walls,
+            State(Coord::new(0, 0)),
+            State(Coord::new(2, 0)),
+            steps,
+            |c| *c != '#',
+            true,
+        );
+        assert_eq!(cost, usize::MAX);
+    }
+}
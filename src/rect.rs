@@ -0,0 +1,264 @@
+use crate::coord::{Coord, Integer};
+
+/// A non-empty rectangle of cells, with both corners inclusive.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rect<N = i32> {
+    pub top_left: Coord<N>,
+    pub bottom_right: Coord<N>,
+}
+
+impl<N: Integer> Rect<N> {
+    pub fn new(top_left: Coord<N>, bottom_right: Coord<N>) -> Self {
+        assert!(
+            top_left.x <= bottom_right.x && top_left.y <= bottom_right.y,
+            "{:?} is not above and left of {:?}",
+            top_left,
+            bottom_right
+        );
+        Self {
+            top_left,
+            bottom_right,
+        }
+    }
+
+    /// The rectangle of `width` x `height` cells starting at `top_left`, or
+    /// `None` when either size is not positive.
+    pub fn with_size(top_left: Coord<N>, width: N, height: N) -> Option<Self> {
+        (width > N::ZERO && height > N::ZERO).then(|| {
+            Self::new(
+                top_left,
+                top_left + Coord::new(width - N::ONE, height - N::ONE),
+            )
+        })
+    }
+
+    /// Smallest rectangle containing every point, or `None` for no points.
+    pub fn bounding_box(points: impl IntoIterator<Item = Coord<N>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut rect = Self::new(first, first);
+        for point in points {
+            rect = rect.union(&Self::new(point, point));
+        }
+        Some(rect)
+    }
+
+    pub fn width(&self) -> N {
+        self.bottom_right.x - self.top_left.x + N::ONE
+    }
+
+    pub fn height(&self) -> N {
+        self.bottom_right.y - self.top_left.y + N::ONE
+    }
+
+    pub fn area(&self) -> N {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, coord: Coord<N>) -> bool {
+        (self.top_left.x..=self.bottom_right.x).contains(&coord.x)
+            && (self.top_left.y..=self.bottom_right.y).contains(&coord.y)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let top_left = Coord::new(
+            self.top_left.x.max(other.top_left.x),
+            self.top_left.y.max(other.top_left.y),
+        );
+        let bottom_right = Coord::new(
+            self.bottom_right.x.min(other.bottom_right.x),
+            self.bottom_right.y.min(other.bottom_right.y),
+        );
+        (top_left.x <= bottom_right.x && top_left.y <= bottom_right.y)
+            .then(|| Self::new(top_left, bottom_right))
+    }
+
+    /// Smallest rectangle containing both.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(
+            Coord::new(
+                self.top_left.x.min(other.top_left.x),
+                self.top_left.y.min(other.top_left.y),
+            ),
+            Coord::new(
+                self.bottom_right.x.max(other.bottom_right.x),
+                self.bottom_right.y.max(other.bottom_right.y),
+            ),
+        )
+    }
+
+    /// Cells in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = Coord<N>> {
+        let rect = *self;
+        let mut next = Some(rect.top_left);
+        std::iter::from_fn(move || {
+            let current = next?;
+            next = if current.x < rect.bottom_right.x {
+                Some(current.right())
+            } else if current.y < rect.bottom_right.y {
+                Some(Coord::new(rect.top_left.x, current.y + N::ONE))
+            } else {
+                None
+            };
+            Some(current)
+        })
+    }
+
+    /// Splits into `columns` x `rows` tiles covering every cell, in reading order.
+    /// When the size does not divide evenly the first tiles are one cell larger.
+    /// Tiles left without cells are `None`, so every slot keeps its position.
+    pub fn tiles(&self, columns: N, rows: N) -> Vec<Option<Self>> {
+        self.split(columns, rows, false)
+    }
+
+    /// Like [`Rect::tiles`], but the lines between tiles belong to none of them,
+    /// e.g. the center row and column when splitting an odd-sized room in four.
+    pub fn tiles_excluding_dividers(&self, columns: N, rows: N) -> Vec<Option<Self>> {
+        self.split(columns, rows, true)
+    }
+
+    /// Top-left, top-right, bottom-left and bottom-right quarters. On an odd
+    /// side the left or top quarters are one cell larger; on a side of one
+    /// cell the right or bottom quarters are `None`.
+    pub fn quadrants(&self) -> [Option<Self>; 4] {
+        Self::four(self.tiles(N::from(2), N::from(2)))
+    }
+
+    /// Quadrants that leave out the middle row and column. On an even side
+    /// there is no single middle, so the line just right of or below the
+    /// center is left out and the left or top quarters are one cell larger,
+    /// e.g. 5 and 4 wide for a width of 10.
+    pub fn quadrants_excluding_center(&self) -> [Option<Self>; 4] {
+        Self::four(self.tiles_excluding_dividers(N::from(2), N::from(2)))
+    }
+
+    fn four(tiles: Vec<Option<Self>>) -> [Option<Self>; 4] {
+        [tiles[0], tiles[1], tiles[2], tiles[3]]
+    }
+
+    fn split(&self, columns: N, rows: N, dividers: bool) -> Vec<Option<Self>> {
+        let xs = spans(self.top_left.x, self.width(), columns, dividers);
+        let ys = spans(self.top_left.y, self.height(), rows, dividers);
+        ys.iter()
+            .flat_map(|&y| {
+                xs.iter().map(move |&x| {
+                    let ((x0, x1), (y0, y1)) = (x?, y?);
+                    Some(Self::new(Coord::new(x0, y0), Coord::new(x1, y1)))
+                })
+            })
+            .collect()
+    }
+}
+
+/// Inclusive ranges cutting `len` cells from `start` into `parts`, with `None` for empty ones.
+fn spans<N: Integer>(start: N, len: N, parts: N, dividers: bool) -> Vec<Option<(N, N)>> {
+    assert!(parts > N::ZERO, "cannot split into {:?} parts", parts);
+    let gap = if dividers { N::ONE } else { N::ZERO };
+    let available = (len - (parts - N::ONE) * gap).max(N::ZERO);
+    let (base, remainder) = (available / parts, available % parts);
+    let mut spans = Vec::new();
+    let mut cursor = start;
+    let mut i = N::ZERO;
+    while i < parts {
+        let size = if i < remainder { base + N::ONE } else { base };
+        spans.push((size > N::ZERO).then(|| (cursor, cursor + size - N::ONE)));
+        cursor = cursor + size + gap;
+        i = i + N::ONE;
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x0: i32, y0: i32, x1: i32, y1: i32) -> Rect {
+        Rect::new(Coord::new(x0, y0), Coord::new(x1, y1))
+    }
+
+    #[test]
+    fn test_measures_and_contains() {
+        let r = Rect::with_size(Coord::new(2, 3), 4, 2).unwrap();
+        assert_eq!(r, rect(2, 3, 5, 4));
+        assert_eq!((r.width(), r.height(), r.area()), (4, 2, 8));
+        assert!(r.contains(Coord::new(5, 4)));
+        assert!(!r.contains(Coord::new(6, 4)));
+        assert_eq!(
+            r.iter().take(5).collect::<Vec<_>>(),
+            vec![
+                Coord::new(2, 3),
+                Coord::new(3, 3),
+                Coord::new(4, 3),
+                Coord::new(5, 3),
+                Coord::new(2, 4)
+            ]
+        );
+        assert_eq!(r.iter().count(), 8);
+        assert_eq!(Rect::with_size(Coord::new(2, 3), 0, 2), None);
+        assert_eq!(Rect::with_size(Coord::new(2, 3), 4, -1), None);
+    }
+
+    #[test]
+    fn test_intersection_union_and_bounding_box() {
+        let (a, b) = (rect(0, 0, 4, 4), rect(3, 2, 8, 9));
+        assert_eq!(a.intersection(&b), Some(rect(3, 2, 4, 4)));
+        assert_eq!(a.intersection(&rect(5, 0, 6, 1)), None);
+        assert_eq!(a.union(&b), rect(0, 0, 8, 9));
+
+        let points = [Coord::new(3, -1), Coord::new(-2, 4), Coord::new(0, 0)];
+        assert_eq!(Rect::bounding_box(points), Some(rect(-2, -1, 3, 4)));
+        assert_eq!(Rect::<i32>::bounding_box([]), None);
+    }
+
+    #[test]
+    fn test_quadrants() {
+        let room = Rect::with_size(Coord::new(0, 0), 11, 7).unwrap();
+        assert_eq!(
+            room.quadrants_excluding_center(),
+            [
+                Some(rect(0, 0, 4, 2)),
+                Some(rect(6, 0, 10, 2)),
+                Some(rect(0, 4, 4, 6)),
+                Some(rect(6, 4, 10, 6))
+            ]
+        );
+        let quadrants = room.quadrants();
+        assert_eq!(quadrants[0], Some(rect(0, 0, 5, 3)));
+        assert_eq!(quadrants[3], Some(rect(6, 4, 10, 6)));
+        assert_eq!(
+            quadrants.iter().flatten().map(|q| q.area()).sum::<i32>(),
+            room.area()
+        );
+
+        let even = Rect::with_size(Coord::new(0, 0), 10, 3).unwrap();
+        assert_eq!(even.quadrants_excluding_center()[0], Some(rect(0, 0, 4, 0)));
+        assert_eq!(even.quadrants_excluding_center()[1], Some(rect(6, 0, 9, 0)));
+        assert_eq!(
+            rect(0, 0, 1, 1).quadrants_excluding_center(),
+            [Some(rect(0, 0, 0, 0)), None, None, None]
+        );
+        assert_eq!(
+            rect(0, 0, 0, 4).quadrants(),
+            [Some(rect(0, 0, 0, 2)), None, Some(rect(0, 3, 0, 4)), None]
+        );
+    }
+
+    #[test]
+    fn test_tiles() {
+        let r = rect(0, 0, 9, 0);
+        let widths: Vec<i32> = r.tiles(3, 1).iter().flatten().map(|t| t.width()).collect();
+        assert_eq!(widths, vec![4, 3, 3]);
+        assert_eq!(
+            r.tiles_excluding_dividers(3, 1),
+            vec![
+                Some(rect(0, 0, 2, 0)),
+                Some(rect(4, 0, 6, 0)),
+                Some(rect(8, 0, 9, 0))
+            ]
+        );
+        assert_eq!(
+            rect(0, 0, 1, 0).tiles(4, 1),
+            vec![Some(rect(0, 0, 0, 0)), Some(rect(1, 0, 1, 0)), None, None]
+        );
+    }
+}
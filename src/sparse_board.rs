@@ -0,0 +1,211 @@
+use crate::board::{Board, Coord, Grid};
+use crate::rect::Rect;
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseBoard<T> {
+    cells: HashMap<Coord, T>,
+    default_value: T,
+    bounds: Option<Rect>,
+}
+
+impl<T> SparseBoard<T> {
+    pub fn new(default_value: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default_value,
+            bounds: None,
+        }
+    }
+
+    /// Limits the area seen through [`Grid`]; cells outside it read as out of bounds.
+    /// Without bounds the grid is infinite, so searches for unreachable targets never end.
+    pub fn with_bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    pub fn bounds(&self) -> Option<Rect> {
+        self.bounds
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default_value
+    }
+
+    /// Returns the stored value, or the default for cells that were never set.
+    pub fn get_value(&self, coord: Coord) -> &T {
+        self.cells.get(&coord).unwrap_or(&self.default_value)
+    }
+
+    pub fn set_value(&mut self, coord: Coord, value: T) {
+        self.cells.insert(coord, value);
+    }
+
+    pub fn remove(&mut self, coord: Coord) -> Option<T> {
+        self.cells.remove(&coord)
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.cells.contains_key(&coord)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        self.cells.iter().map(|(coord, value)| (*coord, value))
+    }
+
+    /// Smallest and largest corner of the cells that have been set, both inclusive.
+    pub fn bounding_box(&self) -> Option<Rect> {
+        Rect::bounding_box(self.cells.keys().copied())
+    }
+}
+
+impl<T: Clone> SparseBoard<T> {
+    /// Dense copy of the bounds, or of the bounding box when there are none, together
+    /// with the coordinate its `(0, 0)` maps to. Cells outside the bounds are left out.
+    pub fn to_board(&self) -> Option<(Board<T>, Coord)> {
+        let rect = self.bounds.or_else(|| self.bounding_box())?;
+        let min = rect.top_left;
+        let (width, height) = (rect.width() as usize, rect.height() as usize);
+        let mut board = Board::from_vec(
+            width,
+            height,
+            vec![self.default_value.clone(); width * height],
+        );
+        for (coord, value) in self
+            .cells
+            .iter()
+            .filter(|(coord, _)| rect.contains(**coord))
+        {
+            board.set_value(*coord - min, value.clone());
+        }
+        Some((board, min))
+    }
+}
+
+impl<T: Clone + PartialEq> SparseBoard<T> {
+    /// Keeps only the cells of `board` that differ from `default_value`,
+    /// bounded by the board's extent.
+    pub fn from_board(board: &Board<T>, default_value: T) -> Self {
+        let cells = board
+            .iter()
+            .filter(|(_, value)| **value != default_value)
+            .map(|(coord, value)| (coord, value.clone()))
+            .collect();
+        Self {
+            cells,
+            default_value,
+            bounds: board.rect(),
+        }
+    }
+}
+
+impl<T: Default> Default for SparseBoard<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Default> FromIterator<(Coord, T)> for SparseBoard<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+            default_value: T::default(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> Grid for SparseBoard<T> {
+    type Cell = T;
+
+    fn get_value(&self, coord: Coord) -> Option<&T> {
+        match self.bounds {
+            Some(bounds) if !bounds.contains(coord) => None,
+            _ => Some(SparseBoard::get_value(self, coord)),
+        }
+    }
+}
+
+impl<T> Index<Coord> for SparseBoard<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get_value(coord)
+    }
+}
+
+impl<T: Clone> IndexMut<Coord> for SparseBoard<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        let default_value = &self.default_value;
+        self.cells
+            .entry(coord)
+            .or_insert_with(|| default_value.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negative_coords_and_default() {
+        let mut board = SparseBoard::new('.');
+        board.set_value(Coord::new(-1_000_000, 5), '#');
+        board[Coord::new(3, -2)] = '@';
+        assert_eq!(board.get_value(Coord::new(-1_000_000, 5)), &'#');
+        assert_eq!(board[Coord::new(0, 0)], '.');
+        assert_eq!(board.len(), 2);
+        assert_eq!(
+            board.bounding_box(),
+            Some(Rect::new(Coord::new(-1_000_000, -2), Coord::new(3, 5)))
+        );
+        assert_eq!(board.remove(Coord::new(3, -2)), Some('@'));
+        assert!(!board.contains(Coord::new(3, -2)));
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let dense = Board::from_string("..#\n#..").unwrap();
+        let sparse = SparseBoard::from_board(&dense, '.');
+        assert_eq!(sparse.len(), 2);
+        let (board, origin) = sparse.to_board().unwrap();
+        assert_eq!(origin, Coord::new(0, 0));
+        assert_eq!(board, dense);
+
+        let framed = Board::from_string("...\n.#.\n...").unwrap();
+        let (board, origin) = SparseBoard::from_board(&framed, '.').to_board().unwrap();
+        assert_eq!(origin, Coord::new(0, 0));
+        assert_eq!(board, framed);
+        let blank = Board::from_string("..\n..").unwrap();
+        let (board, _) = SparseBoard::from_board(&blank, '.').to_board().unwrap();
+        assert_eq!(board, blank);
+
+        let mut clipped =
+            SparseBoard::new('.').with_bounds(Rect::new(Coord::new(0, 0), Coord::new(1, 0)));
+        clipped.set_value(Coord::new(1, 0), '#');
+        clipped.set_value(Coord::new(5, 5), '#');
+        assert_eq!(
+            clipped.to_board().unwrap().0,
+            Board::from_string(".#").unwrap()
+        );
+
+        let shifted: SparseBoard<i32> = [(Coord::new(-2, -1), 4), (Coord::new(-1, -1), 7)]
+            .into_iter()
+            .collect();
+        let (board, origin) = shifted.to_board().unwrap();
+        assert_eq!(origin, Coord::new(-2, -1));
+        assert_eq!((board.width(), board.height()), (2, 1));
+        assert_eq!(board.row(0), &[4, 7]);
+        assert!(SparseBoard::<i32>::default().to_board().is_none());
+    }
+}